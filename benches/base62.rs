@@ -0,0 +1,102 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fuid::{base62, Fuid};
+
+const SHORT: &str = "F0ob4rZ";
+const LONG: &str = "6fTiplVKIi6bJFe8rTXPcu";
+
+const ALPHABET: [u8; 62] = *b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The decoder from version 1.2.2, for comparison.
+fn legacy_decode(string: &str) -> Option<u128> {
+    let mut result: u128 = 0;
+    for (i, c) in string.as_bytes().iter().rev().enumerate() {
+        let num = 62u128.pow(i as u32);
+        let v = ALPHABET.binary_search(c).ok()?;
+        result += (v as u128).checked_mul(num)?;
+    }
+    Some(result)
+}
+
+/// The encoder from version 1.2.2, for comparison.
+fn legacy_encode(mut num: u128) -> String {
+    if num == 0 {
+        return "0".to_owned();
+    }
+    let mut bytes = Vec::new();
+    while num > 0 {
+        bytes.push(ALPHABET[(num % 62) as usize]);
+        num /= 62
+    }
+    bytes.reverse();
+    String::from_utf8(bytes).unwrap()
+}
+
+/// The 1.2.2 encoder writing into a buffer, to compare arithmetic alone.
+fn legacy_encode_to(mut num: u128, buf: &mut [u8; base62::MAX_LEN]) -> usize {
+    let mut start = base62::MAX_LEN;
+    loop {
+        start -= 1;
+        buf[start] = ALPHABET[(num % 62) as usize];
+        num /= 62;
+        if num == 0 {
+            return start;
+        }
+    }
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    for (name, s) in [("short", SHORT), ("long", LONG)] {
+        group.bench_function(format!("table/{}", name), |b| {
+            b.iter(|| base62::decode(black_box(s)).unwrap())
+        });
+        group.bench_function(format!("legacy/{}", name), |b| {
+            b.iter(|| legacy_decode(black_box(s)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+    for (name, s) in [("short", SHORT), ("long", LONG)] {
+        let n = base62::decode(s).unwrap();
+        let mut buf = [0; base62::MAX_LEN];
+        group.bench_function(format!("chunked/{}", name), |b| {
+            b.iter(|| base62::encode_to(black_box(n), &mut buf).len())
+        });
+        group.bench_function(format!("per_digit/{}", name), |b| {
+            b.iter(|| legacy_encode_to(black_box(n), &mut buf))
+        });
+        group.bench_function(format!("legacy/{}", name), |b| {
+            b.iter(|| legacy_encode(black_box(n)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_many(c: &mut Criterion) {
+    let fuids: Vec<Fuid> = (0..10_000).map(|_| Fuid::new()).collect();
+    let mut group = c.benchmark_group("many");
+    group.bench_function("encode_many", |b| {
+        b.iter(|| {
+            let mut out = String::with_capacity(fuids.len() * 23);
+            base62::encode_many(black_box(&fuids), '\n', &mut out).unwrap();
+            out
+        })
+    });
+    group.bench_function("to_string", |b| {
+        b.iter(|| {
+            let mut out = String::with_capacity(fuids.len() * 23);
+            for fuid in black_box(&fuids) {
+                out.push_str(&fuid.to_string());
+                out.push('\n');
+            }
+            out
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_decode, bench_encode, bench_many);
+criterion_main!(benches);
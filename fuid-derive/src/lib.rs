@@ -0,0 +1,135 @@
+//! Derive macro for newtype identifiers wrapping a FUID. Enable the `derive`
+//! feature of the `fuid` crate and use it as `fuid::FuidNewtype` rather than
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, LitStr, Path, Type};
+
+/// Implements the usual FUID conversions and traits for a tuple struct
+/// wrapping a single `Fuid`, such as `struct AccountId(Fuid);`.
+///
+/// With `#[fuid(prefix = "acct")]`, the identifier is formatted, parsed and
+/// serialized with the prefix, like `fuid::PrefixedFuid`. If `fuid` is renamed
+/// or re-exported, `#[fuid(crate = "path::to::fuid")]` sets the path the
+/// expansion uses.
+#[proc_macro_derive(FuidNewtype, attributes(fuid))]
+pub fn derive_fuid_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "FuidNewtype does not support generic types"));
+    }
+    let field = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Unnamed(f) if f.unnamed.len() == 1 => f.unnamed.first(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let field = field.ok_or_else(|| Error::new_spanned(name, "FuidNewtype requires a tuple struct with a single `Fuid` field"))?;
+    match &field.ty {
+        Type::Path(p) if p.qself.is_none() && p.path.segments.last().is_some_and(|s| s.ident == "Fuid" && s.arguments.is_empty()) => {}
+        ty => return Err(Error::new_spanned(ty, "FuidNewtype requires the field to be a `Fuid`")),
+    }
+
+    let mut prefix: Option<LitStr> = None;
+    let mut krate: Path = parse_quote!(::fuid);
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("fuid")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                let lit: LitStr = meta.value()?.parse()?;
+                let value = lit.value();
+                if value.is_empty() || !value.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+                    return Err(Error::new_spanned(&lit, "prefix must be non-empty ASCII letters, digits and underscores"));
+                }
+                prefix = Some(lit);
+                Ok(())
+            } else if meta.path.is_ident("crate") {
+                let lit: LitStr = meta.value()?.parse()?;
+                krate = lit.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unknown fuid attribute, expected `prefix` or `crate`"))
+            }
+        })?;
+    }
+
+    Ok(match prefix {
+        Some(prefix) => quote!(#krate::__newtype!(#name, prefix = #prefix);),
+        None => quote!(#krate::__newtype!(#name);),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_to_string(input: DeriveInput) -> String {
+        match expand(input) {
+            Ok(tokens) => tokens.to_string(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_expand() {
+        assert_eq!(
+            expand_to_string(parse_quote!(struct AccountId(Fuid);)),
+            ":: fuid :: __newtype ! (AccountId) ;"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(#[fuid(prefix = "acct")] struct AccountId(pub fuid::Fuid);)),
+            ":: fuid :: __newtype ! (AccountId , prefix = \"acct\") ;"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(#[fuid(crate = "ids::fuid", prefix = "acct")] struct AccountId(Fuid);)),
+            "ids :: fuid :: __newtype ! (AccountId , prefix = \"acct\") ;"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            expand_to_string(parse_quote!(struct AccountId { id: Fuid })),
+            "FuidNewtype requires a tuple struct with a single `Fuid` field"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(struct AccountId(Fuid, u8);)),
+            "FuidNewtype requires a tuple struct with a single `Fuid` field"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(enum AccountId { A(Fuid) })),
+            "FuidNewtype requires a tuple struct with a single `Fuid` field"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(struct AccountId<T>(Fuid, core::marker::PhantomData<T>);)),
+            "FuidNewtype does not support generic types"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(#[fuid(prefix = "")] struct AccountId(Fuid);)),
+            "prefix must be non-empty ASCII letters, digits and underscores"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(#[fuid(prefix = "a-b")] struct AccountId(Fuid);)),
+            "prefix must be non-empty ASCII letters, digits and underscores"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(#[fuid(name = "acct")] struct AccountId(Fuid);)),
+            "unknown fuid attribute, expected `prefix` or `crate`"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(struct AccountId(u128);)),
+            "FuidNewtype requires the field to be a `Fuid`"
+        );
+        assert_eq!(
+            expand_to_string(parse_quote!(struct AccountId(fuid::PrefixedFuid<AccountId>);)),
+            "FuidNewtype requires the field to be a `Fuid`"
+        );
+    }
+}
@@ -0,0 +1,82 @@
+import_stdlib!();
+
+use crate::radix::Radix;
+
+pub use crate::radix::DecodeError::{self, *};
+
+/// The length of every encoded u128.
+pub const LEN: usize = 26;
+
+/// Crockford's alphabet, which omits `I`, `L`, `O` and `U`. When decoding,
+/// letters may be in either case, `I` and `L` are read as `1`, and `O` is read
+/// as `0`.
+const RADIX: Radix<32> = Radix::new(b"0123456789ABCDEFGHJKMNPQRSTVWXYZ")
+    .case_insensitive()
+    .alias(b'I', b'1')
+    .alias(b'i', b'1')
+    .alias(b'L', b'1')
+    .alias(b'l', b'1')
+    .alias(b'O', b'0')
+    .alias(b'o', b'0');
+
+/// Encodes the number in upper case into the given buffer without
+/// allocating, left-padded with zeros to `LEN` characters.
+pub fn encode_to(num: u128, buf: &mut [u8; LEN]) -> &str {
+    let start = RADIX.encode_digits(num, buf);
+    buf[..start].fill(b'0');
+    core::str::from_utf8(buf).unwrap()
+}
+
+/// Encodes the number in upper case, left-padded with zeros to `LEN`
+/// characters.
+#[cfg(feature = "alloc")]
+pub fn encode(num: u128) -> String {
+    encode_to(num, &mut [0; LEN]).to_owned()
+}
+
+/// Decodes the string into a number. Leading zeros may be omitted.
+pub const fn decode(string: &str) -> Result<u128, DecodeError> {
+    RADIX.decode(string, LEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_to() {
+        let mut buf = [0; LEN];
+        assert_eq!(encode_to(0, &mut buf), "00000000000000000000000000");
+        assert_eq!(encode_to(31, &mut buf), "0000000000000000000000000Z");
+        assert_eq!(encode_to(0x7b06fb9f_cb59_4c6d_a38c_028d27193acd, &mut buf), "3V0VXSZJTS9HPT7302HMKHJEPD");
+        assert_eq!(encode_to(u128::MAX, &mut buf), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    }
+
+    #[test]
+    fn test_decode() -> Result<(), DecodeError> {
+        assert_eq!(decode("3V0VXSZJTS9HPT7302HMKHJEPD")?, 0x7b06fb9f_cb59_4c6d_a38c_028d27193acd);
+        assert_eq!(decode("3v0vxszjts9hpt7302hmkhjepd")?, 0x7b06fb9f_cb59_4c6d_a38c_028d27193acd);
+        assert_eq!(decode("7ZZZZZZZZZZZZZZZZZZZZZZZZZ")?, u128::MAX);
+        assert_eq!(decode("Z")?, 31);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_aliases() -> Result<(), DecodeError> {
+        assert_eq!(decode("1")?, decode("I")?);
+        assert_eq!(decode("1")?, decode("i")?);
+        assert_eq!(decode("1")?, decode("L")?);
+        assert_eq!(decode("1")?, decode("l")?);
+        assert_eq!(decode("10")?, decode("lO")?);
+        assert_eq!(decode("10")?, decode("io")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(matches!(decode(""), Err(Empty)));
+        assert!(matches!(decode("ABU"), Err(InvalidByte('U', 3))));
+        assert!(matches!(decode("80000000000000000000000000"), Err(ValueTooLarge)));
+        assert!(matches!(decode("000000000000000000000000000"), Err(TooLong)));
+    }
+}
@@ -0,0 +1,58 @@
+import_stdlib!();
+
+use crate::radix::Radix;
+
+pub use crate::radix::DecodeError::{self, *};
+
+/// The length of the longest encoded u128.
+pub const MAX_LEN: usize = 25;
+
+const RADIX: Radix<36> = Radix::new(b"0123456789abcdefghijklmnopqrstuvwxyz").case_insensitive();
+
+/// Encodes the number in lower case into the given buffer without allocating,
+/// returning the encoded digits as a string slice of the buffer.
+pub fn encode_to(num: u128, buf: &mut [u8; MAX_LEN]) -> &str {
+    let start = RADIX.encode_digits(num, buf);
+    core::str::from_utf8(&buf[start..]).unwrap()
+}
+
+/// Encodes the number in lower case.
+#[cfg(feature = "alloc")]
+pub fn encode(num: u128) -> String {
+    encode_to(num, &mut [0; MAX_LEN]).to_owned()
+}
+
+/// Decodes the string into a number. Letters may be in either case.
+pub const fn decode(string: &str) -> Result<u128, DecodeError> {
+    RADIX.decode(string, MAX_LEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_to() {
+        let mut buf = [0; MAX_LEN];
+        assert_eq!(encode_to(0, &mut buf), "0");
+        assert_eq!(encode_to(35, &mut buf), "z");
+        assert_eq!(encode_to(0x7b06fb9f_cb59_4c6d_a38c_028d27193acd, &mut buf), "7a7fk7suk5cnmraxvb8wnl39p");
+        assert_eq!(encode_to(u128::MAX, &mut buf), "f5lxx1zz5pnorynqglhzmsp33");
+    }
+
+    #[test]
+    fn test_decode() -> Result<(), DecodeError> {
+        assert_eq!(decode("7a7fk7suk5cnmraxvb8wnl39p")?, 0x7b06fb9f_cb59_4c6d_a38c_028d27193acd);
+        assert_eq!(decode("7A7FK7SUK5CNMRAXVB8WNL39P")?, 0x7b06fb9f_cb59_4c6d_a38c_028d27193acd);
+        assert_eq!(decode("f5lxx1zz5pnorynqglhzmsp33")?, u128::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(matches!(decode(""), Err(Empty)));
+        assert!(matches!(decode("ab-c"), Err(InvalidByte('-', 3))));
+        assert!(matches!(decode("f5lxx1zz5pnorynqglhzmsp34"), Err(ValueTooLarge)));
+        assert!(matches!(decode("00000000000000000000000000"), Err(TooLong)));
+    }
+}
@@ -0,0 +1,58 @@
+import_stdlib!();
+
+use crate::radix::Radix;
+
+pub use crate::radix::DecodeError::{self, *};
+
+/// The length of the longest encoded u128.
+pub const MAX_LEN: usize = 22;
+
+/// The Bitcoin alphabet, which omits `0`, `O`, `I` and `l`.
+const RADIX: Radix<58> = Radix::new(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+
+/// Encodes the number into the given buffer without allocating, returning
+/// the encoded digits as a string slice of the buffer.
+pub fn encode_to(num: u128, buf: &mut [u8; MAX_LEN]) -> &str {
+    let start = RADIX.encode_digits(num, buf);
+    core::str::from_utf8(&buf[start..]).unwrap()
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode(num: u128) -> String {
+    encode_to(num, &mut [0; MAX_LEN]).to_owned()
+}
+
+/// Decodes the string into a number.
+pub const fn decode(string: &str) -> Result<u128, DecodeError> {
+    RADIX.decode(string, MAX_LEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_to() {
+        let mut buf = [0; MAX_LEN];
+        assert_eq!(encode_to(0, &mut buf), "1");
+        assert_eq!(encode_to(57, &mut buf), "z");
+        assert_eq!(encode_to(0x7b06fb9f_cb59_4c6d_a38c_028d27193acd, &mut buf), "GC8fN8757NqTft8MyNVyHW");
+        assert_eq!(encode_to(u128::MAX, &mut buf), "YcVfxkQb6JRzqk5kF2tNLv");
+    }
+
+    #[test]
+    fn test_decode() -> Result<(), DecodeError> {
+        assert_eq!(decode("GC8fN8757NqTft8MyNVyHW")?, 0x7b06fb9f_cb59_4c6d_a38c_028d27193acd);
+        assert_eq!(decode("YcVfxkQb6JRzqk5kF2tNLv")?, u128::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(matches!(decode(""), Err(Empty)));
+        assert!(matches!(decode("ab0c"), Err(InvalidByte('0', 3))));
+        assert!(matches!(decode("abOc"), Err(InvalidByte('O', 3))));
+        assert!(matches!(decode("YcVfxkQb6JRzqk5kF2tNLw"), Err(ValueTooLarge)));
+        assert!(matches!(decode("11111111111111111111111"), Err(TooLong)));
+    }
+}
@@ -1,13 +1,23 @@
 import_stdlib!();
 
+use super::Fuid;
+
 pub use self::DecodeError::*;
 
 // Based on code from https://github.com/fbernier/base62
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum DecodeError {
+    /// The string contains a character outside the alphabet, at the given
+    /// one-based position.
     InvalidBase62Byte(char, usize),
-    ArithmeticOverflow
+    /// The string is empty.
+    Empty,
+    /// The string is longer than `MAX_LEN` characters.
+    TooLong,
+    /// The string encodes a number larger than `u128::MAX`.
+    ValueTooLarge,
 }
 
 impl Error for DecodeError {
@@ -19,70 +29,500 @@ impl fmt::Display for DecodeError {
     }
 }
 
+/// The length of the longest encoded u128, and of every padded encoding.
+pub const MAX_LEN: usize = 22;
+
 const BASE: u128 = 62;
-const ALPHABET: [u8; BASE as usize] = [
-    b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9',
-    b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'I', b'J',
-    b'K', b'L', b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T',
-    b'U', b'V', b'W', b'X', b'Y', b'Z', b'a', b'b', b'c', b'd',
-    b'e', b'f', b'g', b'h', b'i', b'j', b'k', b'l', b'm', b'n',
-    b'o', b'p', b'q', b'r', b's', b't', b'u', b'v', b'w', b'x',
-    b'y', b'z'
-    ];
 
-pub fn encode(mut num: u128) -> String {
-    if num == 0 {
-        return "0".to_owned();
-    }
-    let mut bytes = Vec::new();
+/// The number of digits that always fit in a u64, since 62^10 < 2^64.
+const CHUNK_LEN: usize = 10;
+const CHUNK_BASE: u64 = BASE.pow(CHUNK_LEN as u32) as u64;
 
-    while num > 0 {
-        bytes.push(ALPHABET[(num % BASE) as usize]);
-        num /= BASE
+/// Marks bytes that are not in the alphabet in a decoding table.
+const INVALID: u8 = 0xff;
+
+/// An error returned when creating an `Alphabet` from invalid characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlphabetError {
+    /// The alphabet does not have exactly 62 characters. Holds the length in
+    /// bytes.
+    WrongLength(usize),
+    /// The alphabet contains a byte that is not a printable ASCII character, at
+    /// the given one-based position.
+    NotPrintableAscii(usize),
+    /// The alphabet contains the character more than once, with the second
+    /// occurrence at the given one-based position.
+    Duplicate(char, usize),
+}
+
+impl Error for AlphabetError {
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
     }
-    bytes.reverse();
+}
 
-    String::from_utf8(bytes).unwrap()
+/// The 62 characters used to encode digits, in order of value.
+///
+/// `Alphabet::STANDARD` is the alphabet used for FUIDs, in ASCII order. Other
+/// alphabets can be used to interoperate with other base62 libraries, or
+/// shuffled to obfuscate encoded numbers.
+///
+/// ```
+/// use fuid::base62::{self, Alphabet};
+///
+/// const LETTERS_FIRST: Alphabet =
+///     match Alphabet::new("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789") {
+///         Ok(a) => a,
+///         Err(_) => panic!("invalid alphabet"),
+///     };
+///
+/// let mut buf = [0; base62::MAX_LEN];
+/// assert_eq!(base62::encode_to_with(62, &mut buf, &LETTERS_FIRST), "ba");
+/// assert_eq!(base62::decode_with("ba", &LETTERS_FIRST).unwrap(), 62);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Alphabet {
+    encode: [u8; BASE as usize],
+    /// Maps each byte to its value in the alphabet, or `INVALID`.
+    decode: [u8; 256],
 }
 
-pub fn decode(string: &str) -> Result<u128, DecodeError> {
-    let mut result = 0;
+impl Alphabet {
+    /// Digits, then upper case letters, then lower case letters.
+    pub const STANDARD: Alphabet =
+        match Alphabet::new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz") {
+            Ok(a) => a,
+            Err(_) => panic!("invalid alphabet"),
+        };
 
-    for (i, c) in string.as_bytes().iter().rev().enumerate() {
-        let num = BASE.pow(i as u32);
-        match ALPHABET.binary_search(c) {
-            Ok(v) => {
-                match (v as u128).checked_mul(num) {
-                    Some(z) => result += z,
-                    None => return Err(ArithmeticOverflow),
-                }
+    /// Creates an alphabet from 62 distinct, printable ASCII characters.
+    pub const fn new(chars: &str) -> Result<Alphabet, AlphabetError> {
+        let bytes = chars.as_bytes();
+        if bytes.len() != BASE as usize {
+            return Err(AlphabetError::WrongLength(bytes.len()));
+        }
+
+        let mut encode = [0; BASE as usize];
+        let mut decode = [INVALID; 256];
+        let mut i = 0;
+        while i < bytes.len() {
+            let c = bytes[i];
+            if !c.is_ascii_graphic() {
+                return Err(AlphabetError::NotPrintableAscii(i + 1));
             }
-            Err(_e) => {
-                return Err(InvalidBase62Byte(c.to_owned() as char, string.len() - i));
+            if decode[c as usize] != INVALID {
+                return Err(AlphabetError::Duplicate(c as char, i + 1));
             }
+            encode[i] = c;
+            decode[c as usize] = i as u8;
+            i += 1;
         }
+
+        Ok(Alphabet { encode, decode })
+    }
+
+    /// Returns the characters of the alphabet, in order of value.
+    pub const fn as_str(&self) -> &str {
+        match core::str::from_utf8(&self.encode) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+/// Encodes the number into the given buffer without allocating, returning
+/// the encoded digits as a string slice of the buffer.
+pub fn encode_to(num: u128, buf: &mut [u8; MAX_LEN]) -> &str {
+    encode_to_with(num, buf, &Alphabet::STANDARD)
+}
+
+/// Encodes the number into the given buffer without allocating, left-padded
+/// with zeros to `MAX_LEN` characters.
+pub fn encode_padded_to(num: u128, buf: &mut [u8; MAX_LEN]) -> &str {
+    encode_padded_to_with(num, buf, &Alphabet::STANDARD)
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode(num: u128) -> String {
+    encode_with(num, &Alphabet::STANDARD)
+}
+
+/// Encodes the number left-padded with zeros to `MAX_LEN` characters, so that
+/// padded encodings sort lexicographically in numeric order.
+#[cfg(feature = "alloc")]
+pub fn encode_padded(num: u128) -> String {
+    encode_padded_with(num, &Alphabet::STANDARD)
+}
+
+/// Encodes the number into the given buffer with the given alphabet.
+pub fn encode_to_with<'a>(num: u128, buf: &'a mut [u8; MAX_LEN], alphabet: &Alphabet) -> &'a str {
+    let start = encode_digits(num, buf, alphabet);
+    core::str::from_utf8(&buf[start..]).unwrap()
+}
+
+/// Encodes the number into the given buffer with the given alphabet,
+/// left-padded with its first character to `MAX_LEN` characters. Padded
+/// encodings sort in numeric order only if the alphabet is in ASCII order.
+pub fn encode_padded_to_with<'a>(num: u128, buf: &'a mut [u8; MAX_LEN], alphabet: &Alphabet) -> &'a str {
+    let start = encode_digits(num, buf, alphabet);
+    buf[..start].fill(alphabet.encode[0]);
+    core::str::from_utf8(buf).unwrap()
+}
+
+/// Encodes the number with the given alphabet.
+#[cfg(feature = "alloc")]
+pub fn encode_with(num: u128, alphabet: &Alphabet) -> String {
+    encode_to_with(num, &mut [0; MAX_LEN], alphabet).to_owned()
+}
+
+/// Encodes the number with the given alphabet, left-padded with its first
+/// character to `MAX_LEN` characters.
+#[cfg(feature = "alloc")]
+pub fn encode_padded_with(num: u128, alphabet: &Alphabet) -> String {
+    encode_padded_to_with(num, &mut [0; MAX_LEN], alphabet).to_owned()
+}
+
+/// Writes the digits of the number to the end of the buffer, returning the
+/// index of the first digit.
+fn encode_digits(mut num: u128, buf: &mut [u8; MAX_LEN], alphabet: &Alphabet) -> usize {
+    let alphabet = &alphabet.encode;
+    let mut start = MAX_LEN;
+
+    // Split off limbs of `CHUNK_LEN` digits until the rest fits in a u64, so
+    // that most digits are produced with 64-bit rather than 128-bit division.
+    while num > u64::MAX as u128 {
+        let mut limb = (num % CHUNK_BASE as u128) as u64;
+        num /= CHUNK_BASE as u128;
+        for _ in 0..CHUNK_LEN {
+            start -= 1;
+            buf[start] = alphabet[(limb % BASE as u64) as usize];
+            limb /= BASE as u64;
+        }
+    }
+
+    let mut num = num as u64;
+    loop {
+        start -= 1;
+        buf[start] = alphabet[(num % BASE as u64) as usize];
+        num /= BASE as u64;
+        if num == 0 {
+            return start;
+        }
+    }
+}
+
+/// Decodes the string into a number. This is a `const fn`, so it can be used
+/// to decode strings at compile time.
+pub const fn decode(string: &str) -> Result<u128, DecodeError> {
+    decode_with(string, &Alphabet::STANDARD)
+}
+
+/// Decodes the string into a number with the given alphabet.
+pub const fn decode_with(string: &str, alphabet: &Alphabet) -> Result<u128, DecodeError> {
+    let bytes = string.as_bytes();
+    if bytes.is_empty() {
+        return Err(Empty);
+    }
+    if bytes.len() > MAX_LEN {
+        return Err(TooLong);
+    }
+
+    // Accumulate up to `CHUNK_LEN` digits at a time with u64 arithmetic, then
+    // fold each chunk into the u128 result.
+    let mut result: u128 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let end = if i + CHUNK_LEN < bytes.len() { i + CHUNK_LEN } else { bytes.len() };
+        let mut chunk: u64 = 0;
+        let mut scale: u64 = 1;
+        while i < end {
+            let v = alphabet.decode[bytes[i] as usize];
+            if v == INVALID {
+                return Err(InvalidBase62Byte(bytes[i] as char, i + 1));
+            }
+            chunk = chunk * BASE as u64 + v as u64;
+            scale *= BASE as u64;
+            i += 1;
+        }
+        result = match result.checked_mul(scale as u128) {
+            Some(r) => match r.checked_add(chunk as u128) {
+                Some(r) => r,
+                None => return Err(ValueTooLarge),
+            },
+            None => return Err(ValueTooLarge),
+        };
     }
 
     Ok(result)
 }
 
+/// The size of the buffer that `encode_many` fills before each write.
+const BATCH_LEN: usize = 1024;
+
+/// Encodes each FUID in the slice, separated by `sep`, to the given writer.
+/// This is much faster than formatting each FUID separately, since FUIDs are
+/// encoded in batches into a stack buffer and written together.
+///
+/// ```
+/// use fuid::{base62, fuid};
+///
+/// let mut csv = String::new();
+/// base62::encode_many(&[fuid!(1), fuid!(62)], '\n', &mut csv).unwrap();
+/// assert_eq!(csv, "1\n10");
+/// ```
+pub fn encode_many<W: fmt::Write + ?Sized>(fuids: &[Fuid], sep: char, out: &mut W) -> fmt::Result {
+    let mut batch = [0u8; BATCH_LEN];
+    let mut len = 0;
+    let mut digits = [0u8; MAX_LEN];
+    let mut sep_buf = [0u8; 4];
+    let sep = sep.encode_utf8(&mut sep_buf).as_bytes();
+
+    for (i, fuid) in fuids.iter().enumerate() {
+        if len + sep.len() + MAX_LEN > BATCH_LEN {
+            out.write_str(core::str::from_utf8(&batch[..len]).unwrap())?;
+            len = 0;
+        }
+        if i > 0 {
+            batch[len..len + sep.len()].copy_from_slice(sep);
+            len += sep.len();
+        }
+        let start = encode_digits(fuid.as_u128(), &mut digits, &Alphabet::STANDARD);
+        let n = MAX_LEN - start;
+        batch[len..len + n].copy_from_slice(&digits[start..]);
+        len += n;
+    }
+
+    out.write_str(core::str::from_utf8(&batch[..len]).unwrap())
+}
+
+/// Decodes FUIDs separated by `sep`, returning an iterator over the results.
+/// A trailing separator is ignored.
+///
+/// ```
+/// use fuid::{base62, fuid};
+///
+/// let ids: Result<Vec<_>, _> = base62::decode_many("1\n10\n", '\n').collect();
+/// assert_eq!(ids.unwrap(), [fuid!(1), fuid!(62)]);
+/// ```
+pub fn decode_many(string: &str, sep: char) -> DecodeMany<'_> {
+    DecodeMany(string.split_terminator(sep))
+}
+
+/// An iterator over the FUIDs in a string, created by `decode_many`.
+#[derive(Clone, Debug)]
+pub struct DecodeMany<'a>(core::str::SplitTerminator<'a, char>);
+
+impl Iterator for DecodeMany<'_> {
+    type Item = Result<Fuid, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Fuid::from_str_const)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    import_stdlib!();
 
+    #[cfg(not(feature = "std"))]
+    extern crate alloc;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    #[cfg(all(not(feature = "std"), feature = "alloc"))]
+    use alloc::{string::String, vec::Vec};
+
+    const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_encode() {
         assert_eq!(encode(852751187393), "F0ob4rZ");
     }
 
     #[test]
-    fn test_decode() -> Result<(), Box<dyn Error>> {
+    fn test_encode_chunks() {
+        // Encodes one digit at a time, as the chunked encoder must match.
+        fn reference(mut num: u128, buf: &mut [u8; MAX_LEN]) -> &[u8] {
+            let mut start = MAX_LEN;
+            loop {
+                start -= 1;
+                buf[start] = ALPHABET[(num % BASE) as usize];
+                num /= BASE;
+                if num == 0 {
+                    return &buf[start..];
+                }
+            }
+        }
+
+        let chunk = CHUNK_BASE as u128;
+        let values = [
+            0, 1, 61, 62, chunk - 1, chunk, chunk + 1, u64::MAX as u128, u64::MAX as u128 + 1,
+            chunk * chunk - 1, chunk * chunk, chunk * chunk * 61, u128::MAX - 1, u128::MAX,
+            0x7b06fb9f_cb59_4c6d_a38c_028d27193acd,
+        ];
+        let mut buf = [0; MAX_LEN];
+        let mut expected = [0; MAX_LEN];
+        for n in values {
+            assert_eq!(encode_to(n, &mut buf).as_bytes(), reference(n, &mut expected));
+            assert_eq!(decode(encode_to(n, &mut buf)).unwrap(), n);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_encode_padded() {
+        assert_eq!(encode_padded(0), "0000000000000000000000");
+        assert_eq!(encode_padded(852751187393), "000000000000000F0ob4rZ");
+        assert_eq!(encode_padded(u128::MAX), "7n42DGM5Tflk9n8mt7Fhc7");
+    }
+
+    #[test]
+    fn test_encode_to() {
+        let mut buf = [0; MAX_LEN];
+        assert_eq!(encode_to(0, &mut buf), "0");
+        assert_eq!(encode_to(852751187393, &mut buf), "F0ob4rZ");
+        assert_eq!(encode_to(u128::MAX, &mut buf), "7n42DGM5Tflk9n8mt7Fhc7");
+        assert_eq!(encode_padded_to(852751187393, &mut buf), "000000000000000F0ob4rZ");
+    }
+
+    #[test]
+    fn test_encode_many() {
+        struct Counter(usize, usize, u128);
+
+        // Checks the output by decoding it, without allocating.
+        impl fmt::Write for Counter {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += 1;
+                for part in s.split(',').filter(|p| !p.is_empty()) {
+                    self.2 = self.2.wrapping_add(decode(part).unwrap());
+                }
+                self.1 += s.len();
+                Ok(())
+            }
+        }
+
+        let fuids: [Fuid; 100] = core::array::from_fn(|i| Fuid::with_u128(u128::MAX - i as u128));
+        let mut out = Counter(0, 0, 0);
+        encode_many(&fuids, ',', &mut out).unwrap();
+        assert!(out.0 > 1);
+        assert_eq!(out.1, 100 * 22 + 99);
+        let sum = fuids.iter().fold(0u128, |a, f| a.wrapping_add(f.as_u128()));
+        assert_eq!(out.2, sum);
+
+        let mut out = Counter(0, 0, 0);
+        encode_many(&[], ',', &mut out).unwrap();
+        assert_eq!(out.1, 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_encode_many_roundtrip() {
+        // Spreads the values over the whole range, so the encodings vary in length.
+        const K: u128 = 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835;
+        let fuids: Vec<Fuid> = (0..1000u128).map(|i| Fuid::with_u128(i.wrapping_mul(K))).collect();
+        let mut csv = String::new();
+        encode_many(&fuids, '\n', &mut csv).unwrap();
+        let decoded: Vec<Fuid> = decode_many(&csv, '\n').map(Result::unwrap).collect();
+        assert_eq!(decoded, fuids);
+
+        let mut s = String::new();
+        encode_many(&fuids[..2], '→', &mut s).unwrap();
+        assert_eq!(s, format!("{}→{}", fuids[0], fuids[1]));
+    }
+
+    #[test]
+    fn test_decode_many() {
+        let mut ids = decode_many("1,A,,ab!", ',');
+        assert_eq!(ids.next().unwrap().unwrap(), Fuid::with_u128(1));
+        assert_eq!(ids.next().unwrap().unwrap(), Fuid::with_u128(10));
+        assert!(matches!(ids.next(), Some(Err(Empty))));
+        assert!(matches!(ids.next(), Some(Err(InvalidBase62Byte('!', 3)))));
+        assert!(ids.next().is_none());
+        assert!(decode_many("", ',').next().is_none());
+    }
+
+    #[test]
+    fn test_decode() -> Result<(), DecodeError> {
         assert_eq!(decode("F0ob4rZ")?, 852751187393);
+        assert_eq!(decode("000000000000000F0ob4rZ")?, 852751187393);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_const() {
+        const N: u128 = match decode("F0ob4rZ") {
+            Ok(n) => n,
+            Err(_) => panic!(),
+        };
+        assert_eq!(N, 852751187393);
+    }
+
+    #[test]
+    fn test_decode_alphabet() -> Result<(), DecodeError> {
+        for (i, c) in ALPHABET.iter().enumerate() {
+            assert_eq!(decode(core::str::from_utf8(&[*c]).unwrap())?, i as u128);
+        }
         Ok(())
     }
 
+    #[test]
+    fn test_alphabet() {
+        assert_eq!(Alphabet::STANDARD.as_str().as_bytes(), ALPHABET);
+        assert_eq!(Alphabet::default(), Alphabet::STANDARD);
+
+        let letters_first = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+        let alphabet = Alphabet::new(letters_first).unwrap();
+        assert_eq!(alphabet.as_str(), letters_first);
+
+        let mut buf = [0; MAX_LEN];
+        assert_eq!(encode_to_with(0, &mut buf, &alphabet), "a");
+        assert_eq!(encode_to_with(61, &mut buf, &alphabet), "9");
+        assert_eq!(encode_padded_to_with(1, &mut buf, &alphabet), "aaaaaaaaaaaaaaaaaaaaab");
+        for n in [0, 1, 852751187393, u128::MAX] {
+            assert_eq!(decode_with(encode_to_with(n, &mut buf, &alphabet), &alphabet).unwrap(), n);
+        }
+        assert!(matches!(decode_with("a-b", &alphabet), Err(InvalidBase62Byte('-', 2))));
+    }
+
+    #[test]
+    fn test_alphabet_shuffled() {
+        let shuffled = Alphabet::new("q4XJmZ0bWcT7hVgK2uNfLr9sYxP1eA8dHiM3tBnyGaSjRkC6oEvFz5OlwQpIUD").unwrap();
+        let mut buf = [0; MAX_LEN];
+        let mut std_buf = [0; MAX_LEN];
+        let n = 0x7b06fb9f_cb59_4c6d_a38c_028d27193acd;
+        let encoded = encode_to_with(n, &mut buf, &shuffled);
+        assert_ne!(encoded, encode_to(n, &mut std_buf));
+        assert_eq!(decode_with(encoded, &shuffled).unwrap(), n);
+    }
+
+    #[test]
+    fn test_alphabet_invalid() {
+        assert_eq!(Alphabet::new("0123"), Err(AlphabetError::WrongLength(4)));
+        assert_eq!(
+            Alphabet::new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxy "),
+            Err(AlphabetError::NotPrintableAscii(62))
+        );
+        assert_eq!(
+            Alphabet::new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxé"),
+            Err(AlphabetError::NotPrintableAscii(61))
+        );
+        assert_eq!(
+            Alphabet::new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxy0"),
+            Err(AlphabetError::Duplicate('0', 62))
+        );
+    }
+
     #[test]
     fn test_decode_invalid_char() {
         assert!(decode("ds{Z455f").is_err());
@@ -94,5 +534,21 @@ mod tests {
         assert!(decode("dsZ455fzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz\
                                 zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz")
             .is_err());
+        assert!(matches!(decode("00000000000000000000000"), Err(TooLong)));
+        assert!(matches!(decode("0000000000000000000000000000000000000000000000000"), Err(TooLong)));
+    }
+
+    #[test]
+    fn test_decode_empty() {
+        assert!(matches!(decode(""), Err(Empty)));
+    }
+
+    #[test]
+    fn test_decode_too_large() -> Result<(), DecodeError> {
+        assert_eq!(decode("7n42DGM5Tflk9n8mt7Fhc7")?, u128::MAX);
+        assert!(matches!(decode("7n42DGM5Tflk9n8mt7Fhc8"), Err(ValueTooLarge)));
+        assert!(matches!(decode("7n42DGM5Tflk9n8mt7Fhd0"), Err(ValueTooLarge)));
+        assert!(matches!(decode("zzzzzzzzzzzzzzzzzzzzzz"), Err(ValueTooLarge)));
+        Ok(())
     }
 }
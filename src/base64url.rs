@@ -0,0 +1,93 @@
+import_stdlib!();
+
+use crate::radix::Radix;
+
+pub use crate::radix::DecodeError::{self, *};
+
+/// The length of every encoded u128.
+pub const LEN: usize = 22;
+
+/// The URL-safe alphabet of RFC 4648.
+const DIGITS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const RADIX: Radix<64> = Radix::new(DIGITS);
+
+/// Encodes the number into the given buffer without allocating. The result is
+/// the unpadded URL-safe Base64 encoding of the number's 16 big-endian bytes.
+pub fn encode_to(num: u128, buf: &mut [u8; LEN]) -> &str {
+    // 22 digits hold 132 bits, so the last digit holds the lowest 2 bits of
+    // the number followed by 4 zero bits.
+    for (i, c) in buf[..LEN - 1].iter_mut().enumerate() {
+        *c = DIGITS[(num >> (122 - 6 * i) & 0x3f) as usize];
+    }
+    buf[LEN - 1] = DIGITS[((num & 0x3) << 4) as usize];
+    core::str::from_utf8(buf).unwrap()
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode(num: u128) -> String {
+    encode_to(num, &mut [0; LEN]).to_owned()
+}
+
+/// Decodes an unpadded URL-safe Base64 string of exactly `LEN` characters.
+pub const fn decode(string: &str) -> Result<u128, DecodeError> {
+    let bytes = string.as_bytes();
+    if bytes.is_empty() {
+        return Err(Empty);
+    }
+    if bytes.len() < LEN {
+        return Err(TooShort);
+    }
+    if bytes.len() > LEN {
+        return Err(TooLong);
+    }
+
+    let mut result: u128 = 0;
+    let mut i = 0;
+    while i < LEN {
+        let v = match RADIX.value(bytes[i]) {
+            Some(v) => v as u128,
+            None => return Err(InvalidByte(bytes[i] as char, i + 1)),
+        };
+        if i < LEN - 1 {
+            result = result << 6 | v;
+        } else if v & 0xf != 0 {
+            // The trailing bits would not fit in a u128.
+            return Err(ValueTooLarge);
+        } else {
+            result = result << 2 | v >> 4;
+        }
+        i += 1;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_to() {
+        let mut buf = [0; LEN];
+        assert_eq!(encode_to(0, &mut buf), "AAAAAAAAAAAAAAAAAAAAAA");
+        assert_eq!(encode_to(0x7b06fb9f_cb59_4c6d_a38c_028d27193acd, &mut buf), "ewb7n8tZTG2jjAKNJxk6zQ");
+        assert_eq!(encode_to(u128::MAX, &mut buf), "_____________________w");
+    }
+
+    #[test]
+    fn test_decode() -> Result<(), DecodeError> {
+        assert_eq!(decode("AAAAAAAAAAAAAAAAAAAAAA")?, 0);
+        assert_eq!(decode("ewb7n8tZTG2jjAKNJxk6zQ")?, 0x7b06fb9f_cb59_4c6d_a38c_028d27193acd);
+        assert_eq!(decode("_____________________w")?, u128::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(matches!(decode(""), Err(Empty)));
+        assert!(matches!(decode("ewb7n8tZTG2jjAKNJxk6z"), Err(TooShort)));
+        assert!(matches!(decode("ewb7n8tZTG2jjAKNJxk6zQ=="), Err(TooLong)));
+        assert!(matches!(decode("ewb7n8tZTG2jjAKNJxk+zQ"), Err(InvalidByte('+', 20))));
+        assert!(matches!(decode("_____________________x"), Err(ValueTooLarge)));
+    }
+}
@@ -0,0 +1,121 @@
+//! Adapters for formatting FUIDs in encodings other than base62, and in the
+//! UUID text formats.
+
+import_stdlib!();
+
+use uuid::Uuid;
+use crate::{base32_crockford, base36, base58, base64url, Fuid};
+
+macro_rules! adapter {
+    ($(#[$doc:meta])* $name:ident, $codec:ident, $len:ident) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name(pub(crate) Fuid);
+
+        impl $name {
+            /// Returns the FUID being formatted.
+            pub const fn as_fuid(&self) -> &Fuid {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str($codec::encode_to(self.0.as_u128(), &mut [0; $codec::$len]))
+            }
+        }
+
+        impl From<$name> for Fuid {
+            fn from(a: $name) -> Self {
+                a.0
+            }
+        }
+    };
+}
+
+adapter!(
+    /// Formats a FUID in Bitcoin-style Base58. Created by `Fuid::base58`.
+    Base58, base58, MAX_LEN
+);
+
+adapter!(
+    /// Formats a FUID in lower case base36. Created by `Fuid::base36`.
+    Base36, base36, MAX_LEN
+);
+
+adapter!(
+    /// Formats a FUID in Crockford's Base32, as 26 upper case characters.
+    /// Created by `Fuid::base32_crockford`.
+    Base32Crockford, base32_crockford, LEN
+);
+
+adapter!(
+    /// Formats a FUID in unpadded URL-safe Base64, as 22 characters. Created
+    /// by `Fuid::base64url`.
+    Base64Url, base64url, LEN
+);
+
+macro_rules! uuid_adapter {
+    ($(#[$doc:meta])* $name:ident, $method:ident) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name(pub(crate) Fuid);
+
+        impl $name {
+            /// Returns the FUID being formatted.
+            pub const fn as_fuid(&self) -> &Fuid {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(self, f)
+            }
+        }
+
+        impl fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(&Uuid::from(self.0).$method(), f)
+            }
+        }
+
+        impl fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::UpperHex::fmt(&Uuid::from(self.0).$method(), f)
+            }
+        }
+
+        impl From<$name> for Fuid {
+            fn from(a: $name) -> Self {
+                a.0
+            }
+        }
+    };
+}
+
+uuid_adapter!(
+    /// Formats a FUID as a hyphenated UUID, such as
+    /// `7b06fb9f-cb59-4c6d-a38c-028d27193acd`. Created by `Fuid::hyphenated`.
+    Hyphenated, hyphenated
+);
+
+uuid_adapter!(
+    /// Formats a FUID as a UUID of 32 hex digits, such as
+    /// `7b06fb9fcb594c6da38c028d27193acd`. Created by `Fuid::simple`.
+    Simple, simple
+);
+
+uuid_adapter!(
+    /// Formats a FUID as a UUID URN, such as
+    /// `urn:uuid:7b06fb9f-cb59-4c6d-a38c-028d27193acd`. Created by `Fuid::urn`.
+    Urn, urn
+);
+
+uuid_adapter!(
+    /// Formats a FUID as a braced GUID, such as
+    /// `{7b06fb9f-cb59-4c6d-a38c-028d27193acd}`. Created by `Fuid::braced`.
+    Braced, braced
+);
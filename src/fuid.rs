@@ -1,7 +1,7 @@
 import_stdlib!();
 
-use uuid::Uuid;
-use super::base62;
+use uuid::{Builder, Uuid, Version};
+use super::{base32_crockford, base36, base58, base62, base64url, radix};
 #[cfg(feature = "serde")]
 use serde::{de, Deserialize, Deserializer, Serialize};
 
@@ -11,31 +11,323 @@ use serde::{de, Deserialize, Deserializer, Serialize};
 pub struct Fuid(u128);
 
 impl Fuid {
+    /// The namespace for fully-qualified domain names (`3H8pGALtipnCnHud4zBiky`).
+    pub const NAMESPACE_DNS: Fuid = Fuid(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+
+    /// The namespace for URLs (`3H8pGC0wB7wh6hSYg77M40`).
+    pub const NAMESPACE_URL: Fuid = Fuid(0x6ba7b811_9dad_11d1_80b4_00c04fd430c8);
+
+    /// The namespace for ISO object identifiers (`3H8pGDfydQ6BQ70UHF2zN2`).
+    pub const NAMESPACE_OID: Fuid = Fuid(0x6ba7b812_9dad_11d1_80b4_00c04fd430c8);
+
+    /// The namespace for X.500 distinguished names (`3H8pGH03Y0PA2w6LTUuFz6`).
+    pub const NAMESPACE_X500: Fuid = Fuid(0x6ba7b814_9dad_11d1_80b4_00c04fd430c8);
+
     /// Creates a new, random FUID.
+    #[cfg(feature = "getrandom")]
     pub fn new() -> Fuid {
         Fuid(Uuid::new_v4().as_u128())
     }
 
+    /// Creates a new, random FUID from the given random bytes, setting the UUIDv4
+    /// version and variant bits. Use this to supply randomness from a source
+    /// other than the operating system, such as a hardware RNG.
+    pub const fn from_random_bytes(bytes: [u8; 16]) -> Fuid {
+        Fuid(Builder::from_random_bytes(bytes).into_uuid().as_u128())
+    }
+
+    /// Creates a new, random FUID using the given random number generator. A
+    /// seeded generator produces a reproducible sequence of FUIDs.
+    #[cfg(feature = "rand_core")]
+    pub fn new_with_rng<R: rand_core::Rng + ?Sized>(rng: &mut R) -> Fuid {
+        let mut bytes = [0u8; 16];
+        rng.fill_bytes(&mut bytes);
+        Self::from_random_bytes(bytes)
+    }
+
+    /// Creates a new, time-ordered FUID using the UUIDv7 layout: a 48-bit Unix
+    /// millisecond timestamp, the version and variant bits, and a random tail.
+    /// FUIDs created by the same process with this method sort in creation
+    /// order.
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    pub fn new_v7() -> Fuid {
+        Fuid(Uuid::now_v7().as_u128())
+    }
+
+    /// Creates a new, time-ordered FUID using the UUIDv7 layout and the given
+    /// Unix timestamp in milliseconds. Use this where there is no system clock,
+    /// or to generate FUIDs for a past or future moment.
+    #[cfg(feature = "getrandom")]
+    pub fn new_v7_at(millis: u64) -> Fuid {
+        let r = Uuid::new_v4().into_bytes();
+        // Skip the version and variant bytes of the random UUID.
+        let random = [r[0], r[1], r[2], r[3], r[4], r[5], r[9], r[10], r[11], r[12]];
+        Fuid(Builder::from_unix_timestamp_millis(millis, &random).into_uuid().as_u128())
+    }
+
+    /// Creates a name-based FUID using the UUIDv3 (MD5) layout. The same
+    /// namespace and name always produce the same FUID.
+    #[cfg(feature = "v3")]
+    pub fn new_v3(namespace: Fuid, name: &[u8]) -> Fuid {
+        Fuid(Uuid::new_v3(&namespace.into(), name).as_u128())
+    }
+
+    /// Creates a name-based FUID using the UUIDv5 (SHA-1) layout. The same
+    /// namespace and name always produce the same FUID. Prefer this over
+    /// `new_v3` unless MD5 is needed for compatibility.
+    #[cfg(feature = "v5")]
+    pub fn new_v5(namespace: Fuid, name: &[u8]) -> Fuid {
+        Fuid(Uuid::new_v5(&namespace.into(), name).as_u128())
+    }
+
     /// Creates a new FUID from the given string. FUID-compatible strings may
-    /// include numerals and upper and lower case English letters.
+    /// include numerals and upper and lower case English letters. Both the
+    /// short and the zero-padded forms are accepted.
     pub fn with_str(s: &str) -> Result<Fuid, base62::DecodeError> {
+        Self::from_str_const(s)
+    }
+
+    /// Creates a new FUID from the given string at compile time. This is the
+    /// same as `with_str`, but can be used to declare constants.
+    ///
+    /// ```
+    /// use fuid::Fuid;
+    ///
+    /// pub const ADMIN_ROLE: Fuid = match Fuid::from_str_const("AdminRole") {
+    ///     Ok(f) => f,
+    ///     Err(_) => panic!("invalid FUID"),
+    /// };
+    /// ```
+    pub const fn from_str_const(s: &str) -> Result<Fuid, base62::DecodeError> {
         match base62::decode(s) {
             Ok(n) => Ok(Fuid(n)),
             Err(e) => Err(e),
         }
     }
 
+    /// Creates a new FUID from the given string, panicking if it is not a
+    /// valid FUID. Use this in place of the `From<&str>` conversion of earlier
+    /// versions, where invalid input is a bug.
+    pub const fn from_str_unchecked(s: &str) -> Fuid {
+        match Self::from_str_const(s) {
+            Ok(f) => f,
+            Err(_) => panic!("invalid FUID"),
+        }
+    }
+
+    /// Creates a new FUID from a Bitcoin-style Base58 string.
+    pub const fn from_base58(s: &str) -> Result<Fuid, radix::DecodeError> {
+        match base58::decode(s) {
+            Ok(n) => Ok(Fuid(n)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates a new FUID from a base36 string, in either case.
+    pub const fn from_base36(s: &str) -> Result<Fuid, radix::DecodeError> {
+        match base36::decode(s) {
+            Ok(n) => Ok(Fuid(n)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates a new FUID from a Crockford Base32 string, in either case.
+    pub const fn from_base32_crockford(s: &str) -> Result<Fuid, radix::DecodeError> {
+        match base32_crockford::decode(s) {
+            Ok(n) => Ok(Fuid(n)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Creates a new FUID from a ULID: 26 characters of Crockford's Base32, in
+    /// either case.
+    pub const fn from_ulid_str(s: &str) -> Result<Fuid, radix::DecodeError> {
+        if s.len() < base32_crockford::LEN {
+            return Err(radix::TooShort);
+        }
+        Fuid::from_base32_crockford(s)
+    }
+
+    /// Creates a new FUID from an unpadded URL-safe Base64 string.
+    pub const fn from_base64url(s: &str) -> Result<Fuid, radix::DecodeError> {
+        match base64url::decode(s) {
+            Ok(n) => Ok(Fuid(n)),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Creates a new FUID from the given u128.
-    pub fn with_u128(i: u128) -> Fuid {
+    pub const fn with_u128(i: u128) -> Fuid {
         Self(i)
     }
 
     /// Returns the wrapped u128 value.
-    pub fn as_u128(&self) -> u128 {
+    pub const fn as_u128(&self) -> u128 {
         self.0
     }
+
+    /// Encodes the FUID into the given buffer without allocating, returning the
+    /// encoded string as a slice of the buffer.
+    pub fn encode_to<'a>(&self, buf: &'a mut [u8; base62::MAX_LEN]) -> &'a str {
+        base62::encode_to(self.0, buf)
+    }
+
+    /// Encodes the FUID into the given buffer without allocating, left-padded
+    /// with zeros to 22 characters.
+    pub fn encode_padded_to<'a>(&self, buf: &'a mut [u8; base62::MAX_LEN]) -> &'a str {
+        base62::encode_padded_to(self.0, buf)
+    }
+
+    /// Returns an adapter that formats the FUID in Bitcoin-style Base58.
+    pub const fn base58(self) -> crate::fmt::Base58 {
+        crate::fmt::Base58(self)
+    }
+
+    /// Returns an adapter that formats the FUID in lower case base36.
+    pub const fn base36(self) -> crate::fmt::Base36 {
+        crate::fmt::Base36(self)
+    }
+
+    /// Returns an adapter that formats the FUID in Crockford's Base32.
+    pub const fn base32_crockford(self) -> crate::fmt::Base32Crockford {
+        crate::fmt::Base32Crockford(self)
+    }
+
+    /// Returns an adapter that formats the FUID in unpadded URL-safe Base64.
+    pub const fn base64url(self) -> crate::fmt::Base64Url {
+        crate::fmt::Base64Url(self)
+    }
+
+    /// Returns an adapter that formats the FUID as a hyphenated UUID. Use
+    /// `{:X}` for upper case.
+    pub const fn hyphenated(self) -> crate::fmt::Hyphenated {
+        crate::fmt::Hyphenated(self)
+    }
+
+    /// Returns an adapter that formats the FUID as a UUID of 32 hex digits.
+    /// Use `{:X}` for upper case.
+    pub const fn simple(self) -> crate::fmt::Simple {
+        crate::fmt::Simple(self)
+    }
+
+    /// Returns an adapter that formats the FUID as a UUID URN. Use `{:X}` for
+    /// upper case.
+    pub const fn urn(self) -> crate::fmt::Urn {
+        crate::fmt::Urn(self)
+    }
+
+    /// Returns an adapter that formats the FUID as a braced GUID. Use `{:X}`
+    /// for upper case.
+    pub const fn braced(self) -> crate::fmt::Braced {
+        crate::fmt::Braced(self)
+    }
+
+    /// Returns the FUID as a fixed-width string of 22 characters, left-padded
+    /// with zeros. Unlike the short form, padded strings sort in the same order
+    /// as the FUIDs themselves. This is the same as formatting with `{:#}`.
+    #[cfg(feature = "alloc")]
+    pub fn to_padded_string(&self) -> String {
+        base62::encode_padded(self.0)
+    }
+
+    /// Formats the FUID as a ULID: 26 upper case characters of Crockford's
+    /// Base32. Use `base32_crockford` to format it without allocating.
+    #[cfg(feature = "alloc")]
+    pub fn to_ulid_string(&self) -> String {
+        base32_crockford::encode(self.0)
+    }
+
+    /// Creates a FUID from 16 big-endian bytes, the same layout as
+    /// `Uuid::from_bytes`.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Fuid {
+        Fuid(u128::from_be_bytes(bytes))
+    }
+
+    /// Returns the FUID as 16 big-endian bytes, the same layout as
+    /// `Uuid::as_bytes`.
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    /// Creates a FUID from 16 little-endian bytes.
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> Fuid {
+        Fuid(u128::from_le_bytes(bytes))
+    }
+
+    /// Returns the FUID as 16 little-endian bytes.
+    pub const fn to_le_bytes(&self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
+
+    /// Creates a FUID from 16 bytes in the mixed-endian layout of Microsoft
+    /// GUIDs, such as .NET's `Guid.ToByteArray`, where the first three fields
+    /// are little-endian.
+    pub const fn from_bytes_me(bytes: [u8; 16]) -> Fuid {
+        Self::from_bytes(swap_guid_fields(bytes))
+    }
+
+    /// Returns the FUID as 16 bytes in the mixed-endian layout of Microsoft
+    /// GUIDs, where the first three fields are little-endian.
+    pub const fn to_bytes_me(&self) -> [u8; 16] {
+        swap_guid_fields(self.to_bytes())
+    }
+
+    /// Returns the time this FUID was created as milliseconds since the Unix
+    /// epoch, if it uses a time-based (v1, v6 or v7) UUID layout. Returns
+    /// `None` for random and other FUIDs, and for v1 and v6 FUIDs from before
+    /// 1970.
+    pub fn timestamp_millis(&self) -> Option<u64> {
+        let (secs, nanos) = self.unix_timestamp()?;
+        Some(secs * 1000 + (nanos / 1_000_000) as u64)
+    }
+
+    /// Returns the Unix timestamp in milliseconds held in the first 48 bits,
+    /// where both ULIDs and UUIDv7 store it. Unlike `timestamp_millis`, this
+    /// does not check the version.
+    pub const fn ulid_timestamp_millis(&self) -> u64 {
+        (self.0 >> 80) as u64
+    }
+
+    /// Returns the time this FUID was created, if it uses a time-based (v1, v6
+    /// or v7) UUID layout. Returns `None` for random and other FUIDs, and for
+    /// v1 and v6 FUIDs from before 1970.
+    #[cfg(feature = "std")]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        let (secs, nanos) = self.unix_timestamp()?;
+        Some(UNIX_EPOCH + Duration::new(secs, nanos))
+    }
+
+    /// Returns the seconds and nanoseconds since the Unix epoch of a
+    /// time-based FUID. The v1 and v6 layouts count 100-nanosecond ticks since
+    /// 1582, which `Timestamp::to_unix` does not check are after 1970.
+    fn unix_timestamp(&self) -> Option<(u64, u32)> {
+        let uuid = Uuid::from(*self);
+        let timestamp = uuid.get_timestamp()?;
+        if let Some(Version::Mac | Version::SortMac) = uuid.get_version() {
+            if timestamp.to_gregorian().0 < GREGORIAN_TICKS_BEFORE_UNIX_EPOCH {
+                return None;
+            }
+        }
+        Some(timestamp.to_unix())
+    }
+}
+
+/// The number of 100-nanosecond ticks from the start of the Gregorian calendar
+/// in 1582, where v1 and v6 timestamps start, to the Unix epoch.
+const GREGORIAN_TICKS_BEFORE_UNIX_EPOCH: u64 = 0x01b2_1dd2_1381_4000;
+
+/// Converts between big-endian and GUID mixed-endian byte layouts by
+/// reversing the 4-, 2- and 2-byte fields that GUIDs store little-endian.
+const fn swap_guid_fields(b: [u8; 16]) -> [u8; 16] {
+    [
+        b[3], b[2], b[1], b[0],
+        b[5], b[4],
+        b[7], b[6],
+        b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15],
+    ]
 }
 
+#[cfg(feature = "getrandom")]
 impl Default for Fuid {
     fn default() -> Self {
         Self::new()
@@ -43,15 +335,46 @@ impl Default for Fuid {
 }
 
 impl fmt::Display for Fuid {
+    /// Formats the FUID in its short form, or in its zero-padded, fixed-width
+    /// form when the alternate flag (`{:#}`) is given.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; base62::MAX_LEN];
+        if f.alternate() {
+            f.write_str(self.encode_padded_to(&mut buf))
+        } else {
+            f.write_str(self.encode_to(&mut buf))
+        }
+    }
+}
+
+/// Formats the wrapped u128 value in hexadecimal. Use `{:032x}` for the
+/// fixed-width form.
+impl fmt::LowerHex for Fuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", base62::encode(self.0))
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+/// Formats the wrapped u128 value in upper case hexadecimal. Use `{:032X}` for
+/// the fixed-width form.
+impl fmt::UpperHex for Fuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+/// Formats the wrapped u128 value in binary. Use `{:0128b}` for the
+/// fixed-width form.
+impl fmt::Binary for Fuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
     }
 }
 
 impl fmt::Debug for Fuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("Fuid")
-            .field(&self.to_string())
+            .field(&self.encode_to(&mut [0; base62::MAX_LEN]))
             .finish()
     }
 }
@@ -64,15 +387,29 @@ impl FromStr for Fuid {
     }
 }
 
-impl From<&str> for Fuid {
-    fn from(val: &str) -> Self {
-        Fuid::from_str(val).unwrap()
+impl TryFrom<&str> for Fuid {
+    type Error = base62::DecodeError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        Fuid::with_str(val)
     }
 }
 
-impl From<String> for Fuid {
-    fn from(val: String) -> Self {
-        Fuid::with_str(&val).unwrap()
+#[cfg(feature = "alloc")]
+impl TryFrom<String> for Fuid {
+    type Error = base62::DecodeError;
+
+    fn try_from(val: String) -> Result<Self, Self::Error> {
+        Fuid::with_str(&val)
+    }
+}
+
+/// Fails unless the slice is exactly 16 big-endian bytes.
+impl TryFrom<&[u8]> for Fuid {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Fuid::from_bytes(bytes.try_into()?))
     }
 }
 
@@ -82,6 +419,7 @@ impl From<u128> for Fuid {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<Fuid> for String {
     fn from(f: Fuid) -> Self {
         f.to_string()
@@ -108,20 +446,143 @@ impl From<Fuid> for Uuid {
 
 #[macro_export]
 /// Creates a new FUID from the given expression, usually a string or integer.
+///
+/// String and integer literals are converted at compile time, so the macro can
+/// be used in `const` and `static` items, and an invalid literal is a compile
+/// error:
+///
+/// ```
+/// use fuid::{fuid, Fuid};
+///
+/// const ADMIN: Fuid = fuid!("Admin");
+/// static ROOT: Fuid = fuid!(1);
+/// ```
+///
+/// ```compile_fail
+/// let _ = fuid::fuid!("ab!");
+/// ```
+///
+/// ```compile_fail
+/// let _ = fuid::fuid!("zzzzzzzzzzzzzzzzzzzzzz");
+/// ```
+///
+/// ```compile_fail
+/// let _ = fuid::fuid!("");
+/// ```
+///
+/// Other expressions are converted at run time, and the macro panics if a
+/// string is not a valid FUID.
 macro_rules! fuid {
+    ($s:literal) => {{
+        const FUID: $crate::Fuid = $crate::Fuid::__literal($s).into_fuid();
+        FUID
+    }};
     ($s:expr) => {
-        $crate::Fuid::from($s)
+        $crate::Fuid::__from_macro_arg($s)
     };
 }
 
+/// A literal passed to the `fuid!` macro, converted at compile time.
+#[doc(hidden)]
+pub struct MacroLiteral<T>(T);
+
+impl MacroLiteral<&str> {
+    pub const fn into_fuid(self) -> Fuid {
+        match Fuid::from_str_const(self.0) {
+            Ok(f) => f,
+            Err(base62::InvalidBase62Byte(..)) => panic!("invalid character in FUID literal"),
+            Err(base62::Empty) => panic!("FUID literal is empty"),
+            Err(base62::TooLong) => panic!("FUID literal is too long"),
+            Err(base62::ValueTooLarge) => panic!("FUID literal is too large"),
+        }
+    }
+}
+
+impl MacroLiteral<u128> {
+    pub const fn into_fuid(self) -> Fuid {
+        Fuid(self.0)
+    }
+}
+
+/// The types accepted by the `fuid!` macro.
+#[doc(hidden)]
+pub trait MacroArg {
+    fn into_fuid(self) -> Fuid;
+}
+
+impl MacroArg for &str {
+    fn into_fuid(self) -> Fuid {
+        Fuid::with_str(self).unwrap()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl MacroArg for String {
+    fn into_fuid(self) -> Fuid {
+        Fuid::with_str(&self).unwrap()
+    }
+}
+
+impl MacroArg for u128 {
+    fn into_fuid(self) -> Fuid {
+        Fuid(self)
+    }
+}
+
+impl MacroArg for Uuid {
+    fn into_fuid(self) -> Fuid {
+        self.into()
+    }
+}
+
+impl MacroArg for Fuid {
+    fn into_fuid(self) -> Fuid {
+        self
+    }
+}
+
+impl Fuid {
+    #[doc(hidden)]
+    pub const fn __literal<T>(literal: T) -> MacroLiteral<T> {
+        MacroLiteral(literal)
+    }
+
+    #[doc(hidden)]
+    pub fn __from_macro_arg(arg: impl MacroArg) -> Fuid {
+        arg.into_fuid()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Fuid {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        Fuid::with_str(&s).map_err(de::Error::custom)
+        deserializer.deserialize_str(FuidVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct FuidVisitor;
+
+#[cfg(feature = "serde")]
+impl de::Visitor<'_> for FuidVisitor {
+    type Value = Fuid;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a FUID string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Fuid, E> {
+        Fuid::with_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Fuid, E> {
+        match core::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(v), &self)),
+        }
     }
 }
 
@@ -131,6 +592,6 @@ impl Serialize for Fuid {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        serializer.serialize_str(self.encode_to(&mut [0; base62::MAX_LEN]))
     }
 }
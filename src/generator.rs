@@ -0,0 +1,458 @@
+import_stdlib!();
+
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicU64, Ordering};
+#[cfg(feature = "getrandom")]
+use uuid::Uuid;
+use super::Fuid;
+
+/// A source of the current time for a [`FuidGenerator`].
+///
+/// Any `Fn() -> u64` closure or function returning Unix milliseconds is a
+/// clock, which lets `no_std` targets supply their own time source.
+pub trait Clock {
+    /// Returns the number of milliseconds since the Unix epoch.
+    fn now_millis(&self) -> u64;
+}
+
+impl<F: Fn() -> u64> Clock for F {
+    fn now_millis(&self) -> u64 {
+        self()
+    }
+}
+
+/// A [`Clock`] that reads the system time.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64)
+    }
+}
+
+/// A source of the random bits for a [`FuidGenerator`].
+///
+/// Any `Fn(&mut [u8])` closure that fills the slice with random bytes is a
+/// source, which lets `no_std` targets use a hardware RNG. With the `std` and
+/// `rand_core` features, a `Mutex` holding any `rand_core::Rng` is also a
+/// source.
+pub trait RandomSource {
+    /// Fills the slice with random bytes.
+    fn fill_bytes(&self, dest: &mut [u8]);
+}
+
+impl<F: Fn(&mut [u8])> RandomSource for F {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        self(dest)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "rand_core"))]
+impl<R: rand_core::Rng> RandomSource for std::sync::Mutex<R> {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        self.lock().unwrap_or_else(std::sync::PoisonError::into_inner).fill_bytes(dest)
+    }
+}
+
+/// A [`RandomSource`] that reads the operating system RNG.
+#[cfg(feature = "getrandom")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsRandom;
+
+#[cfg(feature = "getrandom")]
+impl RandomSource for OsRandom {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        // All bytes of a random UUID but the version and variant bytes are
+        // random, so one call to the OS RNG covers the 10 bytes a FUID needs.
+        for chunk in dest.chunks_mut(14) {
+            let uuid = Uuid::new_v4().into_bytes();
+            let mut random = [0; 14];
+            random[..6].copy_from_slice(&uuid[..6]);
+            random[6] = uuid[7];
+            random[7..].copy_from_slice(&uuid[9..]);
+            chunk.copy_from_slice(&random[..chunk.len()]);
+        }
+    }
+}
+
+const COUNTER_BITS: u32 = 16;
+const MILLIS_MASK: u64 = (1 << 48) - 1;
+
+/// Generates strictly increasing, time-ordered FUIDs.
+///
+/// FUIDs use the UUIDv7 layout. The 16 bits following the timestamp, the 12
+/// bits of `rand_a` and the first 4 of `rand_b`, hold a counter that restarts
+/// at zero each millisecond (UUIDv7 "method 1"), so every FUID is greater than
+/// the one generated before it, even within the same millisecond. The
+/// remaining 58 bits are random.
+///
+/// The counter allows 65,536 FUIDs per millisecond. If more are generated in
+/// one millisecond, or the clock goes backwards, the embedded timestamp runs
+/// ahead of the clock until it catches up. The counter and the timestamp share
+/// one 64-bit atomic, which bounds the counter at 16 bits; a wider counter
+/// would leave fewer random bits to keep FUIDs from different generators apart.
+///
+/// The generator is lock-free and can be shared between threads, including in
+/// a `static`. It needs 64-bit atomics. On targets without them, such as
+/// `thumbv6m` and `thumbv7em`, enable the `portable-atomic` feature along with
+/// one of the `portable-atomic` crate's own features for such targets, such as
+/// `critical-section`.
+///
+/// ```
+/// # #[cfg(all(feature = "std", feature = "getrandom"))] {
+/// use fuid::{FuidGenerator, OsRandom, SystemClock};
+///
+/// static GENERATOR: FuidGenerator<SystemClock, OsRandom> = FuidGenerator::new();
+///
+/// let a = GENERATOR.generate();
+/// let b = GENERATOR.generate();
+/// assert!(a < b);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FuidGenerator<C, R> {
+    clock: C,
+    random: R,
+    /// The timestamp and counter of the last generated FUID.
+    last: AtomicU64,
+}
+
+#[cfg(all(feature = "std", feature = "getrandom"))]
+impl FuidGenerator<SystemClock, OsRandom> {
+    /// Creates a generator that reads the system time and the operating system
+    /// RNG.
+    pub const fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "getrandom"))]
+impl Default for FuidGenerator<SystemClock, OsRandom> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl<C: Clock> FuidGenerator<C, OsRandom> {
+    /// Creates a generator that reads the given clock and the operating system
+    /// RNG.
+    pub const fn with_clock(clock: C) -> Self {
+        Self::with_clock_and_random(clock, OsRandom)
+    }
+}
+
+impl<C: Clock, R: RandomSource> FuidGenerator<C, R> {
+    /// Creates a generator that reads the given clock and random source.
+    pub const fn with_clock_and_random(clock: C, random: R) -> Self {
+        Self { clock, random, last: AtomicU64::new(0) }
+    }
+
+    /// Returns a new FUID, greater than every FUID previously returned by
+    /// this generator.
+    pub fn generate(&self) -> Fuid {
+        let (millis, counter) = advance(&self.last, self.clock.now_millis());
+        let random = random_bits(&self.random) & ((1 << 58) - 1);
+        let (counter_a, counter_b) = (counter >> 4, counter & 0xf);
+        Fuid::with_u128(millis << 80 | 0x7 << 76 | counter_a << 64 | 0b10 << 62 | counter_b << 58 | random)
+    }
+}
+
+/// Generates FUIDs with the ULID layout: a 48-bit Unix timestamp in
+/// milliseconds followed by 80 random bits, with no version or variant bits.
+/// Format them as ULIDs with `Fuid::base32_crockford` or
+/// `Fuid::to_ulid_string`.
+///
+/// By default, FUIDs generated in the same millisecond are in random order. In
+/// monotonic mode, the 16 bits following the timestamp hold a counter, as in
+/// [`FuidGenerator`], so every FUID is greater than the one generated before
+/// it. The remaining 64 bits are random.
+///
+/// ```
+/// # #[cfg(all(feature = "std", feature = "getrandom"))] {
+/// use fuid::{OsRandom, SystemClock, UlidGenerator};
+///
+/// static GENERATOR: UlidGenerator<SystemClock, OsRandom> = UlidGenerator::new().monotonic();
+///
+/// let a = GENERATOR.generate();
+/// let b = GENERATOR.generate();
+/// assert!(a < b);
+/// assert_eq!(a.base32_crockford().to_string().len(), 26);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct UlidGenerator<C, R> {
+    clock: C,
+    random: R,
+    monotonic: bool,
+    /// The timestamp and counter of the last generated FUID, in monotonic
+    /// mode.
+    last: AtomicU64,
+}
+
+#[cfg(all(feature = "std", feature = "getrandom"))]
+impl UlidGenerator<SystemClock, OsRandom> {
+    /// Creates a generator that reads the system time and the operating system
+    /// RNG.
+    pub const fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "getrandom"))]
+impl Default for UlidGenerator<SystemClock, OsRandom> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl<C: Clock> UlidGenerator<C, OsRandom> {
+    /// Creates a generator that reads the given clock and the operating system
+    /// RNG.
+    pub const fn with_clock(clock: C) -> Self {
+        Self::with_clock_and_random(clock, OsRandom)
+    }
+}
+
+impl<C: Clock, R: RandomSource> UlidGenerator<C, R> {
+    /// Creates a generator that reads the given clock and random source.
+    pub const fn with_clock_and_random(clock: C, random: R) -> Self {
+        Self { clock, random, monotonic: false, last: AtomicU64::new(0) }
+    }
+
+    /// Switches the generator to monotonic mode.
+    pub const fn monotonic(mut self) -> Self {
+        self.monotonic = true;
+        self
+    }
+
+    /// Returns a new FUID with the ULID layout. In monotonic mode, it is
+    /// greater than every FUID previously returned by this generator.
+    pub fn generate(&self) -> Fuid {
+        let now = self.clock.now_millis();
+        if !self.monotonic {
+            return Fuid::with_u128(((now & MILLIS_MASK) as u128) << 80 | random_bits(&self.random));
+        }
+        let (millis, counter) = advance(&self.last, now);
+        let random = random_bits(&self.random) & ((1 << 64) - 1);
+        Fuid::with_u128(millis << 80 | counter << 64 | random)
+    }
+}
+
+/// Moves the timestamp and counter stored in `last` past both its current
+/// value and the given time, returning the new timestamp and counter.
+fn advance(last: &AtomicU64, now_millis: u64) -> (u128, u128) {
+    let now = (now_millis & MILLIS_MASK) << COUNTER_BITS;
+    let prev = last
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+            Some(if now > last { now } else { last + 1 })
+        })
+        .unwrap();
+    let next = if now > prev { now } else { prev + 1 };
+    ((next >> COUNTER_BITS) as u128, (next & ((1 << COUNTER_BITS) - 1)) as u128)
+}
+
+/// Returns 80 random bits from the source.
+fn random_bits<R: RandomSource>(random: &R) -> u128 {
+    let mut bytes = [0; 16];
+    random.fill_bytes(&mut bytes[6..]);
+    u128::from_be_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::{Uuid, Variant, Version};
+
+    #[cfg(not(feature = "std"))]
+    extern crate alloc;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    /// A deterministic random source, so the tests do not need `getrandom`.
+    fn test_random() -> impl Fn(&mut [u8]) + Sync {
+        let state = AtomicU64::new(0x853c_49e6_748f_ea9b);
+        move |dest: &mut [u8]| {
+            for b in dest {
+                let x = state.fetch_add(0x9e37_79b9_7f4a_7c15, Ordering::Relaxed);
+                *b = (x.wrapping_mul(0xbf58_476d_1ce4_e5b9) >> 56) as u8;
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_millisecond() {
+        let generator = FuidGenerator::with_clock_and_random(|| 1_700_000_000_000, test_random());
+        let mut prev = generator.generate();
+        for _ in 0..10_000 {
+            let next = generator.generate();
+            assert!(next > prev);
+            prev = next;
+        }
+        assert_eq!(prev.timestamp_millis(), Some(1_700_000_000_000));
+    }
+
+    #[test]
+    fn test_counter_overflow() {
+        let generator = FuidGenerator::with_clock_and_random(|| 1_700_000_000_000, test_random());
+        let mut prev = generator.generate();
+        for _ in 0..1 << COUNTER_BITS {
+            let next = generator.generate();
+            assert!(next > prev);
+            prev = next;
+        }
+        assert_eq!(prev.timestamp_millis(), Some(1_700_000_000_001));
+    }
+
+    #[test]
+    fn test_layout() {
+        let generator = FuidGenerator::with_clock_and_random(|| 1_700_000_000_000, test_random());
+        let a = generator.generate();
+        let b = generator.generate();
+        for f in [a, b] {
+            let u = Uuid::from(f);
+            assert_eq!(u.get_version(), Some(Version::SortRand));
+            assert_eq!(u.get_variant(), Variant::RFC4122);
+            assert_eq!(f.timestamp_millis(), Some(1_700_000_000_000));
+        }
+        assert_eq!(a.as_u128() >> 64 & 0xfff, 0);
+        assert_eq!(a.as_u128() >> 58 & 0xf, 0);
+        assert_eq!(b.as_u128() >> 64 & 0xfff, 0);
+        assert_eq!(b.as_u128() >> 58 & 0xf, 1);
+        assert_ne!(a.as_u128() & ((1 << 58) - 1), b.as_u128() & ((1 << 58) - 1));
+    }
+
+    #[test]
+    fn test_clock_goes_backwards() {
+        let now = AtomicU64::new(1_700_000_000_000);
+        let generator = FuidGenerator::with_clock_and_random(|| now.load(Ordering::Relaxed), test_random());
+        let a = generator.generate();
+        now.store(1_600_000_000_000, Ordering::Relaxed);
+        let b = generator.generate();
+        assert!(b > a);
+        assert_eq!(b.timestamp_millis(), Some(1_700_000_000_000));
+        now.store(1_700_000_000_001, Ordering::Relaxed);
+        let c = generator.generate();
+        assert!(c > b);
+        assert_eq!(c.timestamp_millis(), Some(1_700_000_000_001));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_threads() {
+        let generator = FuidGenerator::with_clock_and_random(|| 1_700_000_000_000, test_random());
+        let mut ids: Vec<Fuid> = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| s.spawn(|| (0..1000).map(|_| generator.generate()).collect::<Vec<_>>()))
+                .collect();
+            handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+        });
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 4000);
+    }
+
+    #[test]
+    fn test_ulid_layout() {
+        let generator = UlidGenerator::with_clock_and_random(|| 1_700_000_000_000, test_random());
+        let a = generator.generate();
+        assert_eq!(a.ulid_timestamp_millis(), 1_700_000_000_000);
+        assert_eq!(Fuid::from_ulid_str(&format!("{}", a.base32_crockford())).unwrap(), a);
+
+        let generator = generator.monotonic();
+        let b = generator.generate();
+        let c = generator.generate();
+        assert_eq!(b.ulid_timestamp_millis(), 1_700_000_000_000);
+        assert_eq!(b.as_u128() >> 64 & 0xffff, 0);
+        assert_eq!(c.as_u128() >> 64 & 0xffff, 1);
+    }
+
+    #[test]
+    fn test_ulid_monotonic() {
+        let now = AtomicU64::new(1_700_000_000_000);
+        let generator = UlidGenerator::with_clock_and_random(|| now.load(Ordering::Relaxed), test_random()).monotonic();
+        let mut prev = generator.generate();
+        for _ in 0..10_000 {
+            let next = generator.generate();
+            assert!(next > prev);
+            prev = next;
+        }
+        now.store(1_800_000_000_000, Ordering::Relaxed);
+        let next = generator.generate();
+        assert!(next > prev);
+        assert_eq!(next.ulid_timestamp_millis(), 1_800_000_000_000);
+    }
+
+    #[test]
+    fn test_ulid_random() {
+        let generator = UlidGenerator::with_clock_and_random(|| 1_700_000_000_000, test_random());
+        let a = generator.generate();
+        let b = generator.generate();
+        assert_ne!(a, b);
+        assert_eq!(a.as_u128() >> 80, b.as_u128() >> 80);
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_os_random() {
+        let generator = FuidGenerator::with_clock(|| 1_700_000_000_000);
+        let a = generator.generate();
+        let b = generator.generate();
+        assert!(a < b);
+        assert_ne!(a.as_u128() & ((1 << 58) - 1), b.as_u128() & ((1 << 58) - 1));
+
+        let mut bytes = [0; 20];
+        OsRandom.fill_bytes(&mut bytes);
+        assert_ne!(bytes, [0; 20]);
+    }
+
+    #[cfg(all(feature = "std", feature = "rand_core"))]
+    #[test]
+    fn test_rng_source() {
+        use rand_core::{utils, Infallible, TryRng};
+
+        struct Counter(u64);
+
+        impl TryRng for Counter {
+            type Error = Infallible;
+
+            fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+                self.try_next_u64().map(|n| n as u32)
+            }
+
+            fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+                self.0 += 1;
+                Ok(self.0)
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Infallible> {
+                utils::fill_bytes_via_next_word(dest, || self.try_next_u64())
+            }
+        }
+
+        let generator = FuidGenerator::with_clock_and_random(|| 1_700_000_000_000, std::sync::Mutex::new(Counter(0)));
+        let a = generator.generate();
+        let b = generator.generate();
+        assert!(a < b);
+        assert_ne!(a.as_u128() & ((1 << 58) - 1), b.as_u128() & ((1 << 58) - 1));
+    }
+
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    #[test]
+    fn test_system_clock() {
+        let generator = FuidGenerator::new();
+        let a = generator.generate();
+        let b = generator.generate();
+        assert!(a < b);
+        assert!(a.timestamp().is_some());
+    }
+}
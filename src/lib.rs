@@ -24,20 +24,44 @@
 //!
 //! ```toml
 //! [dependencies.fuid]
-//! version = "1.2.2"
+//! version = "2.0.0"
 //! features = ["serde"]        # Optional: Enable Serde support
 //! ```
 //!
+//! The `v3` and `v5` features enable name-based FUIDs using MD5 and SHA-1
+//! respectively. The `rand_core` feature enables `Fuid::new_with_rng`, which
+//! takes randomness from any `rand_core::Rng` instead of the operating system.
+//! The `derive` feature enables `#[derive(FuidNewtype)]` for newtype
+//! identifiers such as `struct AccountId(Fuid);`.
+//!
+//! Strings convert to FUIDs with the fallible `TryFrom<&str>` and
+//! `TryFrom<String>`. Where invalid input is a bug, `Fuid::from_str_unchecked`
+//! panics instead, like the `From<&str>` conversion of earlier versions.
+//!
 //! # `no_std` Support
 //!
-//! `fuid` supports `no_std` environments. In the `no_std` environment, the
-//! `alloc` crate will be used.
+//! `fuid` supports `no_std` environments. Disable the default features to
+//! depend on `core` only: FUIDs can still be parsed from `&str`, encoded into a
+//! fixed buffer, and formatted with `Display`, all without allocating. Enable
+//! the `alloc` feature to add conversions to and from `String`.
+//!
+//! Random FUIDs from `Fuid::new` use the operating system RNG through the
+//! `getrandom` crate, which is enabled by the default `getrandom` feature and
+//! fails to compile on targets it does not support. Without it, create random
+//! FUIDs from your own source of randomness, such as a hardware RNG, with
+//! `Fuid::from_random_bytes` or `Fuid::new_with_rng`, or give a
+//! `FuidGenerator` a `RandomSource`.
+//!
+//! `FuidGenerator` and `UlidGenerator` need 64-bit atomics. On targets without
+//! them, such as `thumbv6m` and `thumbv7em`, enable the `portable-atomic`
+//! feature, and one of the `portable-atomic` crate's features for such
+//! targets, such as `critical-section`.
 //!
 //! ```toml
 //! [dependencies.fuid]
-//! version = "1.2.2"
+//! version = "2.0.0"
 //! default-features = false    # Disable default features
-//! features = ["serde"]        # Optional: Enable Serde support with no_std
+//! features = ["alloc"]        # Optional: Enable String conversions
 //! ```
 //!
 //! # Usage
@@ -46,6 +70,7 @@
 //!
 //! ```
 //! # fn main() {
+//! # #[cfg(feature = "getrandom")]
 //! # {
 //! use fuid::Fuid;
 //!
@@ -72,11 +97,58 @@
 //! 3k9FL4LZe71geQdbOyCvz3
 //! ```
 //!
+//! When FUIDs are used as database keys, random values scatter inserts across
+//! the index. Time-ordered FUIDs use the UUIDv7 layout instead: a 48-bit Unix
+//! millisecond timestamp followed by random bits, so they sort by creation
+//! time.
+//!
+//! ```
+//! # fn main() {
+//! # #[cfg(all(feature = "std", feature = "getrandom"))]
+//! # {
+//! use fuid::Fuid;
+//! use uuid::{Uuid, Version};
+//!
+//! let a = Fuid::new_v7();
+//! let b = Fuid::new_v7();
+//! assert!(a < b);
+//! assert_eq!(Uuid::from(a).get_version(), Some(Version::SortRand));
+//!
+//! // The creation time can be read back from time-ordered FUIDs.
+//! assert!(a.timestamp().is_some());
+//! assert!(Fuid::new().timestamp().is_none());
+//! # }
+//! # }
+//! ```
+//!
+//! To guarantee that FUIDs sort in the order they were generated, even when
+//! many are generated in the same millisecond, use a `FuidGenerator`. In
+//! `no_std` environments, a generator can be created with any clock that
+//! returns Unix milliseconds and any source of random bytes.
+//!
+//! ```
+//! # fn main() {
+//! # {
+//! use fuid::FuidGenerator;
+//!
+//! let generator = FuidGenerator::with_clock_and_random(
+//!     || 1_700_000_000_000,
+//!     |bytes: &mut [u8]| bytes.fill(0x5a), // Read a hardware RNG here.
+//! );
+//! let a = generator.generate();
+//! let b = generator.generate();
+//! assert!(a < b);
+//! assert_eq!(a.timestamp_millis(), Some(1_700_000_000_000));
+//! # }
+//! # }
+//! ```
+//!
 //! You can convert short strings to and from FUIDs. FUID-compatible strings may
 //! include numerals and upper and lower case English letters.
 //!
 //! ```
 //! # fn main() {
+//! # #[cfg(feature = "alloc")]
 //! # {
 //! # use std::str::FromStr;
 //! use fuid::Fuid;
@@ -92,6 +164,135 @@
 //! # }
 //! ```
 //!
+//! Short strings do not sort in the same order as the FUIDs they represent.
+//! Where that matters, such as for keys in a key-value store, use the
+//! fixed-width form, which is left-padded with zeros to 22 characters. Parsing
+//! accepts both forms.
+//!
+//! ```
+//! # fn main() {
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use fuid::{fuid, Fuid};
+//!
+//! let id = fuid!(1);
+//! assert_eq!(id.to_string(), "1");
+//! assert_eq!(format!("{:#}", id), "0000000000000000000001");
+//! assert_eq!(id.to_padded_string(), "0000000000000000000001");
+//! assert_eq!(Fuid::with_str("0000000000000000000001").unwrap(), id);
+//! # }
+//! # }
+//! ```
+//!
+//! FUIDs can be encoded into a stack buffer without allocating. Formatting
+//! with `Display` never allocates.
+//!
+//! ```
+//! # fn main() {
+//! # {
+//! use fuid::fuid;
+//!
+//! let mut buf = [0u8; 22];
+//! assert_eq!(fuid!(852751187393).encode_to(&mut buf), "F0ob4rZ");
+//! # }
+//! # }
+//! ```
+//!
+//! FUIDs can also be formatted and parsed in other encodings: Crockford's
+//! Base32, which is case-insensitive and avoids easily confused letters, so it
+//! is suited to reading aloud; Bitcoin-style Base58; base36; and URL-safe
+//! Base64.
+//!
+//! ```
+//! # fn main() {
+//! # {
+//! use fuid::{fuid, Fuid};
+//!
+//! let id = fuid!("3k9FL4LZe71geQdbOyCvz3");
+//! assert_eq!(id.base32_crockford().to_string(), "3V0VXSZJTS9HPT7302HMKHJEPD");
+//! assert_eq!(id.base58().to_string(), "GC8fN8757NqTft8MyNVyHW");
+//! assert_eq!(id.base36().to_string(), "7a7fk7suk5cnmraxvb8wnl39p");
+//! assert_eq!(id.base64url().to_string(), "ewb7n8tZTG2jjAKNJxk6zQ");
+//! assert_eq!(Fuid::from_base32_crockford("3v0vxszjts9hpt7302hmkhjepd").unwrap(), id);
+//! # }
+//! # }
+//! ```
+//!
+//! To keep identifiers of different kinds of entity apart, tag them with
+//! `TypedFuid<T>`. Typed FUIDs with different tags cannot be compared or
+//! assigned to each other, but otherwise behave like `Fuid`.
+//!
+//! ```
+//! # fn main() {
+//! # {
+//! use fuid::TypedFuid;
+//!
+//! enum User {}
+//! type UserId = TypedFuid<User>;
+//!
+//! let id: UserId = "A".parse().unwrap();
+//! assert_eq!(id.erase().as_u128(), 10);
+//! # }
+//! # }
+//! ```
+//!
+//! For string IDs that carry their type, such as `usr_A`, implement `Prefix`
+//! for the tag and use `PrefixedFuid<T>`. Parsing checks the prefix, and
+//! serde uses the prefixed form.
+//!
+//! ```
+//! # fn main() {
+//! # {
+//! use fuid::{Prefix, PrefixedFuid};
+//!
+//! enum User {}
+//!
+//! impl Prefix for User {
+//!     const PREFIX: &'static str = "usr";
+//! }
+//!
+//! let id: PrefixedFuid<User> = "usr_A".parse().unwrap();
+//! assert_eq!(id.erase().as_u128(), 10);
+//! assert_eq!(id.to_string(), "usr_A");
+//! assert!("A".parse::<PrefixedFuid<User>>().is_err());
+//! # }
+//! # }
+//! ```
+//!
+//! FUIDs convert losslessly to and from [TypeID](https://github.com/jetify-com/typeid)s,
+//! which pair a prefix with 26 characters of lower case Crockford's Base32.
+//!
+//! ```
+//! # fn main() {
+//! # {
+//! use fuid::Fuid;
+//!
+//! let (prefix, id) = Fuid::from_typeid("user_01h455vb4pex5vsknk084sn02q").unwrap();
+//! assert_eq!(prefix, "user");
+//! assert_eq!(id.typeid(prefix).unwrap().to_string(), "user_01h455vb4pex5vsknk084sn02q");
+//! # }
+//! # }
+//! ```
+//!
+//! ULIDs are also 128 bits, so they convert losslessly too. `UlidGenerator`
+//! generates FUIDs with the ULID layout, optionally in monotonic order.
+//!
+//! ```
+//! # fn main() {
+//! # #[cfg(all(feature = "std", feature = "getrandom"))]
+//! # {
+//! use fuid::{Fuid, UlidGenerator};
+//!
+//! let id = Fuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+//! assert_eq!(id.to_ulid_string(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+//! assert_eq!(id.ulid_timestamp_millis(), 1_469_922_850_259);
+//!
+//! let generator = UlidGenerator::new().monotonic();
+//! assert!(generator.generate() < generator.generate());
+//! # }
+//! # }
+//! ```
+//!
 //! You can convert unsigned integers to and from FUIDs.
 //!
 //! ```
@@ -107,7 +308,10 @@
 //! # }
 //! ```
 //!
-//! You can use the `fuid!` macro to easily convert literals into FUIDs.
+//! You can use the `fuid!` macro to easily convert literals into FUIDs. Literals
+//! are checked at compile time, and the macro can be used to declare constants.
+//! `Fuid::from_str_const`, `Fuid::with_u128` and `Fuid::as_u128` are also
+//! `const fn`s.
 //!
 //! ```
 //! # fn main() {
@@ -115,7 +319,7 @@
 //! use fuid::fuid;
 //!
 //! let a = fuid!("A");
-//! assert_eq!(String::from(a), "A");
+//! assert_eq!(a.to_string(), "A");
 //!
 //! let b = fuid!(1);
 //! assert_eq!(b.as_u128(), 1);
@@ -137,7 +341,56 @@
 //! let fuid = Fuid::from_str(f).unwrap();
 //! let uuid = Uuid::from_str(u).unwrap();
 //! assert_eq!(fuid, uuid.into());
-//! assert_eq!(uuid, fuid.into());
+//! assert_eq!(uuid, Uuid::from(fuid));
+//! # }
+//! # }
+//! ```
+//!
+//! FUIDs can be formatted as UUIDs without converting them first, for
+//! example to log both forms side by side.
+//!
+//! ```
+//! # fn main() {
+//! # {
+//! use fuid::fuid;
+//!
+//! let id = fuid!("3k9FL4LZe71geQdbOyCvz3");
+//! assert_eq!(id.hyphenated().to_string(), "7b06fb9f-cb59-4c6d-a38c-028d27193acd");
+//! assert_eq!(format!("{:X}", id.simple()), "7B06FB9FCB594C6DA38C028D27193ACD");
+//! assert_eq!(format!("{:x}", id), "7b06fb9fcb594c6da38c028d27193acd");
+//! # }
+//! # }
+//! ```
+//!
+//! FUIDs can be converted to and from 16 bytes: big-endian like
+//! `Uuid::as_bytes`, little-endian, or in the mixed-endian layout of Microsoft
+//! GUIDs.
+//!
+//! ```
+//! # fn main() {
+//! # {
+//! use fuid::{fuid, Fuid};
+//!
+//! let id = fuid!("3k9FL4LZe71geQdbOyCvz3");
+//! assert_eq!(Fuid::from_bytes(id.to_bytes()), id);
+//! assert_eq!(Fuid::from_bytes_me(id.to_bytes_me()), id);
+//! assert_eq!(Fuid::try_from(&id.to_bytes()[..]).unwrap(), id);
+//! # }
+//! # }
+//! ```
+//!
+//! When both UUIDs and FUIDs may be received, `Fuid::parse_any` accepts base62
+//! as well as hyphenated, simple, URN and braced UUIDs, and reports which
+//! format it found.
+//!
+//! ```
+//! # fn main() {
+//! # {
+//! use fuid::{Format, Fuid};
+//!
+//! let (fuid, format) = Fuid::parse_any("urn:uuid:7b06fb9f-cb59-4c6d-a38c-028d27193acd").unwrap();
+//! assert_eq!(fuid.to_string(), "3k9FL4LZe71geQdbOyCvz3");
+//! assert_eq!(format, Format::Urn);
 //! # }
 //! # }
 //! ```
@@ -148,7 +401,77 @@ mod stdlib;
 mod fuid;
 pub use fuid::Fuid;
 
+mod parse;
+pub use parse::{Format, ParseError};
+
+mod typed;
+pub use typed::TypedFuid;
+
+mod prefixed;
+pub use prefixed::{Prefix, PrefixError, PrefixedFuid};
+
+pub mod typeid;
+
+#[cfg(feature = "derive")]
+mod newtype;
+/// Implements the usual FUID conversions and traits for a tuple struct
+/// wrapping a single `Fuid`: `new`, `as_fuid`, `Display`, `Debug`, `FromStr`,
+/// `TryFrom<&str>`, conversions to and from `Fuid` and `Uuid`, and, with the
+/// `serde` feature, `Serialize` and `Deserialize`. Traits such as `Clone`,
+/// `Eq` and `Hash` can be derived as usual.
+///
+/// With `#[fuid(prefix = "acct")]`, the identifier is formatted, parsed and
+/// serialized with the prefix, like `PrefixedFuid`. If this crate is renamed
+/// or re-exported, `#[fuid(crate = "path::to::fuid")]` sets the path the
+/// generated code refers to it by.
+///
+/// ```
+/// use fuid::{Fuid, FuidNewtype};
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, FuidNewtype)]
+/// struct AccountId(Fuid);
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, FuidNewtype)]
+/// #[fuid(prefix = "acct")]
+/// struct PrefixedAccountId(Fuid);
+///
+/// let id: AccountId = "A".parse().unwrap();
+/// assert_eq!(id.as_fuid().as_u128(), 10);
+///
+/// let id: PrefixedAccountId = "acct_A".parse().unwrap();
+/// assert_eq!(id.to_string(), "acct_A");
+/// assert!("A".parse::<PrefixedAccountId>().is_err());
+/// ```
+#[cfg(feature = "derive")]
+pub use fuid_derive::FuidNewtype;
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::newtype::Repr;
+    pub use crate::prefixed::Quoted;
+    pub use uuid::Uuid;
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
+
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+mod generator;
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+pub use generator::{Clock, FuidGenerator, RandomSource, UlidGenerator};
+#[cfg(all(any(target_has_atomic = "64", feature = "portable-atomic"), feature = "getrandom"))]
+pub use generator::OsRandom;
+#[cfg(all(any(target_has_atomic = "64", feature = "portable-atomic"), feature = "std"))]
+pub use generator::SystemClock;
+
 pub mod base62;
+pub mod base58;
+pub mod base36;
+pub mod base32_crockford;
+pub mod base64url;
+mod radix;
+
+pub mod fmt;
 
 #[cfg(test)]
 mod tests {
@@ -158,8 +481,12 @@ mod tests {
     extern crate alloc;
 
     #[cfg(not(feature = "std"))]
-    use alloc::string::{String, ToString};
+    use alloc::format;
+
+    #[cfg(all(not(feature = "std"), feature = "alloc"))]
+    use alloc::{string::{String, ToString}, vec::Vec};
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_fuid() {
         let a = "6fTiplVKIi6bJFe8rTXPcu";
@@ -171,15 +498,233 @@ mod tests {
         assert_eq!(fa.to_string(), a);
         assert_eq!(fb.to_string(), b);
 
+        #[cfg(feature = "getrandom")]
         assert_ne!(Fuid::new(), fa);
+        #[cfg(feature = "getrandom")]
         assert_ne!(Fuid::new(), fb);
 
         assert!(Fuid::with_str("ab!").is_err());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let a: Fuid = "A".try_into().unwrap();
+        assert_eq!(a.as_u128(), 10);
+        assert!(matches!(Fuid::try_from("ab!"), Err(crate::base62::InvalidBase62Byte('!', 3))));
+
+        #[cfg(feature = "alloc")]
+        {
+            let b: Fuid = "A".to_string().try_into().unwrap();
+            assert_eq!(a, b);
+            assert!(Fuid::try_from("ab!".to_string()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_from_str_unchecked() {
+        assert_eq!(Fuid::from_str_unchecked("A").as_u128(), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_str_unchecked_invalid() {
+        let _ = Fuid::from_str_unchecked("ab!");
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_new_v7() {
+        use uuid::{Uuid, Version};
+
+        let a = Fuid::new_v7_at(0x0123_4567_89ab);
+        let u = Uuid::from(a);
+        assert_eq!(u.get_version(), Some(Version::SortRand));
+        assert_eq!(a.as_u128() >> 80, 0x0123_4567_89ab);
+        assert_eq!(Fuid::from(u), a);
+
+        let b = Fuid::new_v7_at(0x0123_4567_89ac);
+        assert!(a < b);
+    }
 
-        let _: Fuid = "A".into();
-        let _: Fuid = "A".to_string().into();
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    #[test]
+    fn test_new_v7_ordered() {
+        let ids: Vec<Fuid> = (0..100).map(|_| Fuid::new_v7()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_timestamp_millis() {
+        use uuid::Uuid;
+
+        #[cfg(feature = "getrandom")]
+        assert_eq!(Fuid::new_v7_at(1_700_000_000_123).timestamp_millis(), Some(1_700_000_000_123));
+        #[cfg(feature = "getrandom")]
+        assert_eq!(Fuid::new().timestamp_millis(), None);
+        assert_eq!(fuid!(1).timestamp_millis(), None);
+
+        let v1: Fuid = Uuid::from_u128(0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6).into();
+        assert_eq!(v1.timestamp_millis(), Some(854_991_792_216));
+    }
+
+    #[test]
+    fn test_timestamp_before_1970() {
+        use uuid::Uuid;
+
+        let v1: Fuid = Uuid::from_u128(0x00000001_0000_1000_8000_000000000000).into();
+        assert_eq!(v1.timestamp_millis(), None);
+        let v6: Fuid = Uuid::from_u128(0x00000000_0001_6000_8000_000000000000).into();
+        assert_eq!(v6.timestamp_millis(), None);
+
+        // The first tick of 1970 is the Unix epoch.
+        let epoch: Fuid = Uuid::from_u128(0x13814000_1dd2_11b2_8000_000000000000).into();
+        assert_eq!(epoch.timestamp_millis(), Some(0));
+        let before: Fuid = Uuid::from_u128(0x13813fff_1dd2_11b2_8000_000000000000).into();
+        assert_eq!(before.timestamp_millis(), None);
+
+        #[cfg(feature = "std")]
+        assert_eq!(v1.timestamp(), None);
+    }
+
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    #[test]
+    fn test_timestamp() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let a = Fuid::new_v7_at(1_700_000_000_123);
+        assert_eq!(a.timestamp(), Some(UNIX_EPOCH + Duration::from_millis(1_700_000_000_123)));
+
+        let before = SystemTime::now() - Duration::from_millis(1);
+        let b = Fuid::new_v7().timestamp().unwrap();
+        assert!(b >= before && b <= SystemTime::now());
+
+        assert_eq!(Fuid::new().timestamp(), None);
+    }
+
+    #[test]
+    fn test_namespaces() {
+        use uuid::Uuid;
+
+        assert_eq!(Fuid::NAMESPACE_DNS, fuid!("3H8pGALtipnCnHud4zBiky"));
+        assert_eq!(Fuid::NAMESPACE_URL, fuid!("3H8pGC0wB7wh6hSYg77M40"));
+        assert_eq!(Fuid::NAMESPACE_OID, fuid!("3H8pGDfydQ6BQ70UHF2zN2"));
+        assert_eq!(Fuid::NAMESPACE_X500, fuid!("3H8pGH03Y0PA2w6LTUuFz6"));
+
+        assert_eq!(Uuid::from(Fuid::NAMESPACE_DNS), Uuid::NAMESPACE_DNS);
+        assert_eq!(Uuid::from(Fuid::NAMESPACE_URL), Uuid::NAMESPACE_URL);
+        assert_eq!(Uuid::from(Fuid::NAMESPACE_OID), Uuid::NAMESPACE_OID);
+        assert_eq!(Uuid::from(Fuid::NAMESPACE_X500), Uuid::NAMESPACE_X500);
     }
 
+    #[cfg(feature = "v3")]
+    #[test]
+    fn test_new_v3() {
+        let a = Fuid::new_v3(Fuid::NAMESPACE_DNS, b"example.com");
+        assert_eq!(a, fuid!("4OZi7SAelC641YjxkxEHet"));
+        assert_eq!(a, Fuid::new_v3(Fuid::NAMESPACE_DNS, b"example.com"));
+        assert_ne!(a, Fuid::new_v3(Fuid::NAMESPACE_URL, b"example.com"));
+    }
+
+    #[cfg(feature = "v5")]
+    #[test]
+    fn test_new_v5() {
+        let a = Fuid::new_v5(Fuid::NAMESPACE_DNS, b"example.com");
+        assert_eq!(a, fuid!("6K17zDsJ5mt63ZSqJEv8Pn"));
+        assert_eq!(a, Fuid::new_v5(Fuid::NAMESPACE_DNS, b"example.com"));
+        assert_ne!(a, Fuid::new_v5(Fuid::NAMESPACE_URL, b"example.com"));
+    }
+
+    #[test]
+    fn test_from_random_bytes() {
+        use uuid::{Uuid, Variant, Version};
+
+        let a = Fuid::from_random_bytes([0xff; 16]);
+        let u = Uuid::from(a);
+        assert_eq!(u.get_version(), Some(Version::Random));
+        assert_eq!(u.get_variant(), Variant::RFC4122);
+        assert_eq!(a, Fuid::from_random_bytes([0xff; 16]));
+        assert_ne!(a, Fuid::from_random_bytes([0; 16]));
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_new_with_rng() {
+        use rand_core::{utils, Infallible, TryRng};
+
+        struct XorShift(u64);
+
+        impl TryRng for XorShift {
+            type Error = Infallible;
+
+            fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+                self.try_next_u64().map(|n| n as u32)
+            }
+
+            fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                Ok(self.0)
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Infallible> {
+                utils::fill_bytes_via_next_word(dest, || self.try_next_u64())
+            }
+        }
+
+        let mut a = XorShift(42);
+        let mut b = XorShift(42);
+        let fa = Fuid::new_with_rng(&mut a);
+        assert_eq!(fa, Fuid::new_with_rng(&mut b));
+        assert_ne!(fa, Fuid::new_with_rng(&mut a));
+        assert_eq!(uuid::Uuid::from(fa).get_version(), Some(uuid::Version::Random));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_padded() {
+        let mut ids = [fuid!(62), fuid!("6fTiplVKIi6bJFe8rTXPcu"), fuid!(1), fuid!("3k9FL4LZe71geQdbOyCvz3"), fuid!(u128::MAX), fuid!(0)];
+        let mut strings: Vec<String> = ids.iter().map(Fuid::to_padded_string).collect();
+        ids.sort();
+        strings.sort();
+        for (id, s) in ids.iter().zip(&strings) {
+            assert_eq!(s.len(), 22);
+            assert_eq!(*s, format!("{:#}", id));
+            assert_eq!(Fuid::with_str(s).unwrap(), *id);
+        }
+    }
+
+    #[test]
+    fn test_core_only() {
+        use core::fmt::Write;
+
+        struct Buf([u8; 64], usize);
+
+        impl Write for Buf {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+                self.1 += s.len();
+                Ok(())
+            }
+        }
+
+        let a = Fuid::with_str("6fTiplVKIi6bJFe8rTXPcu").unwrap();
+        let mut buf = Buf([0; 64], 0);
+        write!(buf, "{} {:#}", a, fuid!(1)).unwrap();
+        assert_eq!(&buf.0[..buf.1], b"6fTiplVKIi6bJFe8rTXPcu 0000000000000000000001");
+    }
+
+    #[test]
+    fn test_encode_to() {
+        let a = fuid!("6fTiplVKIi6bJFe8rTXPcu");
+        let mut buf = [0; 22];
+        assert_eq!(a.encode_to(&mut buf), "6fTiplVKIi6bJFe8rTXPcu");
+        assert_eq!(fuid!(1).encode_to(&mut buf), "1");
+        assert_eq!(fuid!(1).encode_padded_to(&mut buf), "0000000000000000000001");
+        assert_eq!(format!("{:?}", fuid!("A")), "Fuid(\"A\")");
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_macro() {
         let a = fuid!("A");
@@ -187,6 +732,133 @@ mod tests {
 
         let b = fuid!(1);
         assert_eq!(b.as_u128(), 1);
+
+        let s = "B";
+        assert_eq!(fuid!(s).as_u128(), 11);
+        assert_eq!(fuid!(s.to_string()).as_u128(), 11);
+        assert_eq!(fuid!(uuid::Uuid::from_u128(12)).as_u128(), 12);
+    }
+
+    #[test]
+    fn test_macro_const() {
+        const A: Fuid = fuid!("6fTiplVKIi6bJFe8rTXPcu");
+        static B: Fuid = fuid!(10);
+        assert_eq!(A, Fuid::with_str("6fTiplVKIi6bJFe8rTXPcu").unwrap());
+        assert_eq!(B, Fuid::with_str("A").unwrap());
+    }
+
+    #[test]
+    fn test_const_fns() {
+        const A: Fuid = Fuid::with_u128(10);
+        const B: u128 = A.as_u128();
+        const C: Result<Fuid, crate::base62::DecodeError> = Fuid::from_str_const("A");
+        const D: Result<Fuid, crate::base62::DecodeError> = Fuid::from_str_const("ab!");
+        assert_eq!(B, 10);
+        assert_eq!(C.unwrap(), A);
+        assert!(D.is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_macro_invalid() {
+        let s = "ab!";
+        fuid!(s);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_alternative_encodings() {
+        for id in [fuid!(0), fuid!(1), fuid!("3k9FL4LZe71geQdbOyCvz3"), fuid!(u128::MAX)] {
+            assert_eq!(Fuid::from_base58(&id.base58().to_string()).unwrap(), id);
+            assert_eq!(Fuid::from_base36(&id.base36().to_string()).unwrap(), id);
+            assert_eq!(Fuid::from_base32_crockford(&id.base32_crockford().to_string()).unwrap(), id);
+            assert_eq!(Fuid::from_base64url(&id.base64url().to_string()).unwrap(), id);
+            assert_eq!(Fuid::from(id.base58()), id);
+            assert_eq!(*id.base36().as_fuid(), id);
+        }
+        assert!(Fuid::from_base58("0").is_err());
+        assert!(Fuid::from_base32_crockford("U").is_err());
+    }
+
+    #[test]
+    fn test_ulid() {
+        let id = Fuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        assert_eq!(Fuid::from_ulid_str("01arz3ndektsv4rrffq69g5fav").unwrap(), id);
+        assert_eq!(format!("{}", id.base32_crockford()), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert_eq!(id.ulid_timestamp_millis(), 1_469_922_850_259);
+        assert_eq!(Fuid::from_ulid_str("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap(), fuid!(u128::MAX));
+        assert!(matches!(Fuid::from_ulid_str("1"), Err(crate::base32_crockford::TooShort)));
+        assert!(matches!(Fuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAVX"), Err(crate::base32_crockford::TooLong)));
+        assert!(matches!(Fuid::from_ulid_str("80000000000000000000000000"), Err(crate::base32_crockford::ValueTooLarge)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_ulid_string() {
+        let id = fuid!("3k9FL4LZe71geQdbOyCvz3");
+        assert_eq!(Fuid::from_ulid_str(&id.to_ulid_string()).unwrap(), id);
+        assert_eq!(fuid!(1).to_ulid_string(), "00000000000000000000000001");
+    }
+
+    #[test]
+    fn test_uuid_formats() {
+        let id = fuid!("3k9FL4LZe71geQdbOyCvz3");
+        assert_eq!(format!("{}", id.hyphenated()), "7b06fb9f-cb59-4c6d-a38c-028d27193acd");
+        assert_eq!(format!("{:X}", id.hyphenated()), "7B06FB9F-CB59-4C6D-A38C-028D27193ACD");
+        assert_eq!(format!("{}", id.simple()), "7b06fb9fcb594c6da38c028d27193acd");
+        assert_eq!(format!("{}", id.urn()), "urn:uuid:7b06fb9f-cb59-4c6d-a38c-028d27193acd");
+        assert_eq!(format!("{:X}", id.urn()), "urn:uuid:7B06FB9F-CB59-4C6D-A38C-028D27193ACD");
+        assert_eq!(format!("{}", id.braced()), "{7b06fb9f-cb59-4c6d-a38c-028d27193acd}");
+        assert_eq!(*id.braced().as_fuid(), id);
+        assert_eq!(Fuid::from(id.urn()), id);
+
+        let formatted = [
+            format!("{}", id.hyphenated()),
+            format!("{}", id.simple()),
+            format!("{}", id.urn()),
+            format!("{}", id.braced()),
+        ];
+        for f in formatted {
+            assert_eq!(Fuid::parse_any(&f).unwrap().0, id);
+        }
+    }
+
+    #[test]
+    fn test_hex_and_binary() {
+        let id = fuid!("3k9FL4LZe71geQdbOyCvz3");
+        assert_eq!(format!("{:x}", id), "7b06fb9fcb594c6da38c028d27193acd");
+        assert_eq!(format!("{:X}", id), "7B06FB9FCB594C6DA38C028D27193ACD");
+        assert_eq!(format!("{:#x}", fuid!(255)), "0xff");
+        assert_eq!(format!("{:032x}", fuid!(255)), "000000000000000000000000000000ff");
+        assert_eq!(format!("{:b}", fuid!(5)), "101");
+        assert_eq!(format!("{:b}", id).len(), 127);
+    }
+
+    #[test]
+    fn test_bytes() {
+        use uuid::Uuid;
+
+        let id = fuid!("3k9FL4LZe71geQdbOyCvz3");
+        let uuid = Uuid::from(id);
+        let be = [0x7b, 0x06, 0xfb, 0x9f, 0xcb, 0x59, 0x4c, 0x6d, 0xa3, 0x8c, 0x02, 0x8d, 0x27, 0x19, 0x3a, 0xcd];
+        let me = [0x9f, 0xfb, 0x06, 0x7b, 0x59, 0xcb, 0x6d, 0x4c, 0xa3, 0x8c, 0x02, 0x8d, 0x27, 0x19, 0x3a, 0xcd];
+
+        assert_eq!(id.to_bytes(), be);
+        assert_eq!(id.to_bytes(), *uuid.as_bytes());
+        assert_eq!(Fuid::from_bytes(be), id);
+
+        let mut le = be;
+        le.reverse();
+        assert_eq!(id.to_le_bytes(), le);
+        assert_eq!(Fuid::from_le_bytes(le), id);
+
+        assert_eq!(id.to_bytes_me(), me);
+        assert_eq!(id.to_bytes_me(), uuid.to_bytes_le());
+        assert_eq!(Fuid::from_bytes_me(me), id);
+
+        assert_eq!(Fuid::try_from(&be[..]).unwrap(), id);
+        assert!(Fuid::try_from(&be[..15]).is_err());
+        assert!(Fuid::try_from(&[0u8; 17][..]).is_err());
     }
 
     #[cfg(feature = "serde")]
@@ -194,9 +866,13 @@ mod tests {
     fn test_serde() {
         use serde_json::{to_string, from_str};
 
-        let a = Fuid::new();
+        let a = fuid!("3k9FL4LZe71geQdbOyCvz3");
         let b = to_string(&a).unwrap();
         let c: Fuid = from_str(&b).unwrap();
         assert_eq!(a, c);
+
+        use serde::{de::value::{BytesDeserializer, Error}, Deserialize};
+        assert_eq!(Fuid::deserialize(BytesDeserializer::<Error>::new(b"3k9FL4LZe71geQdbOyCvz3")).unwrap(), a);
+        assert!(Fuid::deserialize(BytesDeserializer::<Error>::new(b"\xff")).is_err());
     }
 }
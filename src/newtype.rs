@@ -0,0 +1,168 @@
+use super::{Fuid, Prefix, PrefixedFuid};
+
+/// The type a `#[derive(FuidNewtype)]` identifier is formatted, parsed and
+/// serialized as: `Fuid`, or `PrefixedFuid` when it has a prefix.
+#[doc(hidden)]
+pub trait Repr {
+    fn from_fuid(fuid: Fuid) -> Self;
+    fn into_fuid(self) -> Fuid;
+}
+
+impl Repr for Fuid {
+    fn from_fuid(fuid: Fuid) -> Self {
+        fuid
+    }
+
+    fn into_fuid(self) -> Fuid {
+        self
+    }
+}
+
+impl<P: Prefix + ?Sized> Repr for PrefixedFuid<P> {
+    fn from_fuid(fuid: Fuid) -> Self {
+        PrefixedFuid::from_fuid(fuid)
+    }
+
+    fn into_fuid(self) -> Fuid {
+        self.erase()
+    }
+}
+
+/// The expansion of `#[derive(FuidNewtype)]`. It lives here rather than in
+/// `fuid-derive` so that it follows the features of this crate.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype {
+    ($name:ident, prefix = $prefix:literal) => {
+        impl $crate::Prefix for $name {
+            const PREFIX: &'static str = $prefix;
+        }
+
+        $crate::__newtype!(@impl $name, $crate::PrefixedFuid<$name>);
+    };
+    ($name:ident) => {
+        $crate::__newtype!(@impl $name, $crate::Fuid);
+    };
+    (@impl $name:ident, $repr:ty) => {
+        $crate::__newtype_new!($name);
+
+        impl $name {
+            /// Returns the wrapped FUID.
+            pub const fn as_fuid(&self) -> &$crate::Fuid {
+                &self.0
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&<$repr as $crate::__private::Repr>::from_fuid(self.0), f)
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(::core::stringify!($name))
+                    .field(&$crate::__private::Quoted(self))
+                    .finish()
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = <$repr as ::core::str::FromStr>::Err;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                <$repr as ::core::str::FromStr>::from_str(s)
+                    .map(|r| Self($crate::__private::Repr::into_fuid(r)))
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = <$repr as ::core::str::FromStr>::Err;
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(s)
+            }
+        }
+
+        impl ::core::convert::From<$crate::Fuid> for $name {
+            fn from(fuid: $crate::Fuid) -> Self {
+                Self(fuid)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::Fuid {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl ::core::convert::From<$crate::__private::Uuid> for $name {
+            fn from(uuid: $crate::__private::Uuid) -> Self {
+                Self(uuid.into())
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::__private::Uuid {
+            fn from(id: $name) -> Self {
+                id.0.into()
+            }
+        }
+
+        $crate::__newtype_serde!($name, $repr);
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_serde {
+    ($name:ident, $repr:ty) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                let repr = <$repr as $crate::__private::Repr>::from_fuid(self.0);
+                $crate::__private::serde::Serialize::serialize(&repr, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                <$repr as $crate::__private::serde::Deserialize>::deserialize(deserializer)
+                    .map(|r| Self($crate::__private::Repr::into_fuid(r)))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_serde {
+    ($name:ident, $repr:ty) => {};
+}
+
+#[cfg(feature = "getrandom")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_new {
+    ($name:ident) => {
+        impl $name {
+            /// Creates a new, random identifier.
+            pub fn new() -> Self {
+                Self($crate::Fuid::new())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "getrandom"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_new {
+    ($name:ident) => {};
+}
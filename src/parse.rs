@@ -0,0 +1,115 @@
+import_stdlib!();
+
+use uuid::Uuid;
+use super::{base62, Fuid};
+
+/// The text formats recognized by `Fuid::parse_any`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// A base62 FUID, such as `3k9FL4LZe71geQdbOyCvz3`.
+    Base62,
+    /// A hyphenated UUID, such as `7b06fb9f-cb59-4c6d-a38c-028d27193acd`.
+    Hyphenated,
+    /// A UUID of 32 hex digits, such as `7b06fb9fcb594c6da38c028d27193acd`.
+    Simple,
+    /// A UUID URN, such as `urn:uuid:7b06fb9f-cb59-4c6d-a38c-028d27193acd`.
+    Urn,
+    /// A braced GUID, such as `{7b06fb9f-cb59-4c6d-a38c-028d27193acd}`.
+    Braced,
+}
+
+/// An error returned by `Fuid::parse_any`.
+#[derive(Debug)]
+pub enum ParseError {
+    /// The string looked like a FUID, but was not valid base62.
+    Base62(base62::DecodeError),
+    /// The string looked like a UUID, but was not valid.
+    Uuid(uuid::Error),
+}
+
+impl Error for ParseError {
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Base62(e) => write!(f, "invalid FUID: {}", e),
+            ParseError::Uuid(e) => write!(f, "invalid UUID: {}", e),
+        }
+    }
+}
+
+impl Format {
+    /// Guesses the format of the string from its shape, without validating it.
+    fn detect(s: &str) -> Format {
+        if s.starts_with("urn:uuid:") {
+            Format::Urn
+        } else if s.starts_with('{') {
+            Format::Braced
+        } else if s.len() == 36 {
+            Format::Hyphenated
+        } else if s.len() == 32 {
+            Format::Simple
+        } else {
+            Format::Base62
+        }
+    }
+}
+
+impl Fuid {
+    /// Parses a FUID from either base62 or any of the common UUID text
+    /// formats, returning the format that was detected. This eases migrating
+    /// from UUIDs, since an API can accept both for the same field.
+    ///
+    /// ```
+    /// use fuid::{fuid, Format, Fuid};
+    ///
+    /// let id = fuid!("3k9FL4LZe71geQdbOyCvz3");
+    /// assert_eq!(Fuid::parse_any("3k9FL4LZe71geQdbOyCvz3").unwrap(), (id, Format::Base62));
+    /// assert_eq!(
+    ///     Fuid::parse_any("7b06fb9f-cb59-4c6d-a38c-028d27193acd").unwrap(),
+    ///     (id, Format::Hyphenated)
+    /// );
+    /// ```
+    pub fn parse_any(s: &str) -> Result<(Fuid, Format), ParseError> {
+        let format = Format::detect(s);
+        let fuid = match format {
+            Format::Base62 => Fuid::with_str(s).map_err(ParseError::Base62)?,
+            _ => Uuid::try_parse(s).map_err(ParseError::Uuid)?.into(),
+        };
+        Ok((fuid, format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: Fuid = Fuid::with_u128(0x7b06fb9f_cb59_4c6d_a38c_028d27193acd);
+
+    #[test]
+    fn test_parse_any() {
+        let cases = [
+            ("3k9FL4LZe71geQdbOyCvz3", Format::Base62),
+            ("7b06fb9f-cb59-4c6d-a38c-028d27193acd", Format::Hyphenated),
+            ("7B06FB9F-CB59-4C6D-A38C-028D27193ACD", Format::Hyphenated),
+            ("7b06fb9fcb594c6da38c028d27193acd", Format::Simple),
+            ("urn:uuid:7b06fb9f-cb59-4c6d-a38c-028d27193acd", Format::Urn),
+            ("{7b06fb9f-cb59-4c6d-a38c-028d27193acd}", Format::Braced),
+        ];
+        for (s, format) in cases {
+            assert_eq!(Fuid::parse_any(s).unwrap(), (ID, format));
+        }
+        assert_eq!(Fuid::parse_any("A").unwrap(), (Fuid::with_u128(10), Format::Base62));
+    }
+
+    #[test]
+    fn test_parse_any_invalid() {
+        assert!(matches!(Fuid::parse_any(""), Err(ParseError::Base62(base62::Empty))));
+        assert!(matches!(Fuid::parse_any("ab!"), Err(ParseError::Base62(_))));
+        assert!(matches!(Fuid::parse_any("7b06fb9f-cb59-4c6d-a38c-028d27193acz"), Err(ParseError::Uuid(_))));
+        assert!(matches!(Fuid::parse_any("7b06fb9fcb594c6da38c028d27193acz"), Err(ParseError::Uuid(_))));
+        assert!(matches!(Fuid::parse_any("urn:uuid:7b06fb9f"), Err(ParseError::Uuid(_))));
+        assert!(matches!(Fuid::parse_any("{7b06fb9f-cb59-4c6d-a38c-028d27193acd"), Err(ParseError::Uuid(_))));
+    }
+}
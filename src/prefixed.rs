@@ -0,0 +1,336 @@
+import_stdlib!();
+
+use core::{cmp::Ordering, hash::{Hash, Hasher}};
+use uuid::Uuid;
+use super::{base62, Fuid, TypedFuid};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+/// The type prefix of a `PrefixedFuid`, such as `usr` in
+/// `usr_6fTiplVKIi6bJFe8rTXPcu`.
+pub trait Prefix {
+    /// The prefix, without the separator.
+    const PREFIX: &'static str;
+
+    /// The separator between the prefix and the FUID.
+    const SEPARATOR: char = '_';
+}
+
+/// An error returned when parsing a `PrefixedFuid`.
+#[derive(Debug)]
+pub enum PrefixError {
+    /// The string does not start with the expected prefix and separator.
+    WrongPrefix,
+    /// The part after the prefix is not a valid FUID.
+    Fuid(base62::DecodeError),
+}
+
+impl Error for PrefixError {
+}
+
+impl fmt::Display for PrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrefixError::WrongPrefix => f.write_str("wrong or missing ID prefix"),
+            PrefixError::Fuid(e) => write!(f, "invalid FUID: {}", e),
+        }
+    }
+}
+
+/// A FUID that is formatted, parsed and serialized with a type prefix, such as
+/// `usr_6fTiplVKIi6bJFe8rTXPcu`.
+///
+/// Parsing fails unless the string has the expected prefix, so an ID of one
+/// kind cannot be mistaken for another. Like `TypedFuid`, prefixed FUIDs with
+/// different prefixes cannot be compared or assigned to each other.
+///
+/// ```
+/// use fuid::{Prefix, PrefixedFuid};
+///
+/// enum User {}
+///
+/// impl Prefix for User {
+///     const PREFIX: &'static str = "usr";
+/// }
+///
+/// type UserId = PrefixedFuid<User>;
+///
+/// let id: UserId = "usr_6fTiplVKIi6bJFe8rTXPcu".parse().unwrap();
+/// assert_eq!(id.to_string(), "usr_6fTiplVKIi6bJFe8rTXPcu");
+/// assert!("ord_6fTiplVKIi6bJFe8rTXPcu".parse::<UserId>().is_err());
+/// assert!("6fTiplVKIi6bJFe8rTXPcu".parse::<UserId>().is_err());
+/// ```
+#[repr(transparent)]
+pub struct PrefixedFuid<P: Prefix + ?Sized>(TypedFuid<P>);
+
+impl<P: Prefix + ?Sized> PrefixedFuid<P> {
+    /// Creates a new, random FUID.
+    #[cfg(feature = "getrandom")]
+    pub fn new() -> Self {
+        Self::from_fuid(Fuid::new())
+    }
+
+    /// Creates a new, time-ordered FUID using the UUIDv7 layout.
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    pub fn new_v7() -> Self {
+        Self::from_fuid(Fuid::new_v7())
+    }
+
+    /// Tags the given FUID with the prefix.
+    pub const fn from_fuid(fuid: Fuid) -> Self {
+        Self(TypedFuid::from_fuid(fuid))
+    }
+
+    /// Parses a prefixed FUID. The part after the prefix may be in the short
+    /// or the zero-padded form.
+    pub fn with_str(s: &str) -> Result<Self, PrefixError> {
+        let body = s
+            .strip_prefix(P::PREFIX)
+            .and_then(|rest| rest.strip_prefix(P::SEPARATOR))
+            .ok_or(PrefixError::WrongPrefix)?;
+        Fuid::with_str(body).map(Self::from_fuid).map_err(PrefixError::Fuid)
+    }
+
+    /// Returns the wrapped u128 value.
+    pub const fn as_u128(&self) -> u128 {
+        self.0.as_u128()
+    }
+
+    /// Returns the FUID without its prefix.
+    pub const fn erase(self) -> Fuid {
+        self.0.erase()
+    }
+
+    /// Returns the FUID as a `TypedFuid`, which formats without the prefix.
+    pub const fn into_typed(self) -> TypedFuid<P> {
+        self.0
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl<P: Prefix + ?Sized> Default for PrefixedFuid<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Prefix + ?Sized> Clone for PrefixedFuid<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: Prefix + ?Sized> Copy for PrefixedFuid<P> {
+}
+
+impl<P: Prefix + ?Sized> PartialEq for PrefixedFuid<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<P: Prefix + ?Sized> Eq for PrefixedFuid<P> {
+}
+
+impl<P: Prefix + ?Sized> PartialOrd for PrefixedFuid<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Prefix + ?Sized> Ord for PrefixedFuid<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<P: Prefix + ?Sized> Hash for PrefixedFuid<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<P: Prefix + ?Sized> fmt::Display for PrefixedFuid<P> {
+    /// Formats the prefix, the separator and the FUID. The alternate flag
+    /// (`{:#}`) selects the zero-padded form of the FUID.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(P::PREFIX)?;
+        fmt::Write::write_char(f, P::SEPARATOR)?;
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<P: Prefix + ?Sized> fmt::Debug for PrefixedFuid<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PrefixedFuid")
+            .field(&Quoted(self))
+            .finish()
+    }
+}
+
+/// Formats a value with `Display` in quotes, so that IDs appear in `Debug`
+/// output the same way as the string field of `Fuid`'s, without allocating.
+#[doc(hidden)]
+pub struct Quoted<T>(pub T);
+
+impl<T: fmt::Display> fmt::Debug for Quoted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.0)
+    }
+}
+
+impl<P: Prefix + ?Sized> FromStr for PrefixedFuid<P> {
+    type Err = PrefixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::with_str(s)
+    }
+}
+
+impl<P: Prefix + ?Sized> TryFrom<&str> for PrefixedFuid<P> {
+    type Error = PrefixError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        Self::with_str(val)
+    }
+}
+
+impl<P: Prefix + ?Sized> From<TypedFuid<P>> for PrefixedFuid<P> {
+    fn from(f: TypedFuid<P>) -> Self {
+        Self(f)
+    }
+}
+
+impl<P: Prefix + ?Sized> From<PrefixedFuid<P>> for TypedFuid<P> {
+    fn from(f: PrefixedFuid<P>) -> Self {
+        f.0
+    }
+}
+
+impl<P: Prefix + ?Sized> From<Uuid> for PrefixedFuid<P> {
+    fn from(u: Uuid) -> Self {
+        Self::from_fuid(u.into())
+    }
+}
+
+impl<P: Prefix + ?Sized> From<PrefixedFuid<P>> for Uuid {
+    fn from(f: PrefixedFuid<P>) -> Self {
+        f.erase().into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P: Prefix + ?Sized> Deserialize<'de> for PrefixedFuid<P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(PrefixedFuidVisitor(core::marker::PhantomData))
+    }
+}
+
+#[cfg(feature = "serde")]
+struct PrefixedFuidVisitor<P: ?Sized>(core::marker::PhantomData<fn() -> P>);
+
+#[cfg(feature = "serde")]
+impl<P: Prefix + ?Sized> de::Visitor<'_> for PrefixedFuidVisitor<P> {
+    type Value = PrefixedFuid<P>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a FUID string with the prefix `{}{}`", P::PREFIX, P::SEPARATOR)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<PrefixedFuid<P>, E> {
+        PrefixedFuid::with_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<PrefixedFuid<P>, E> {
+        match core::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P: Prefix + ?Sized> Serialize for PrefixedFuid<P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate alloc;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    enum User {}
+
+    impl Prefix for User {
+        const PREFIX: &'static str = "usr";
+    }
+
+    enum Order {}
+
+    impl Prefix for Order {
+        const PREFIX: &'static str = "order";
+        const SEPARATOR: char = '-';
+    }
+
+    #[test]
+    fn test_prefixed_fuid() {
+        let a: PrefixedFuid<User> = "usr_6fTiplVKIi6bJFe8rTXPcu".parse().unwrap();
+        assert_eq!(a.erase(), Fuid::with_str("6fTiplVKIi6bJFe8rTXPcu").unwrap());
+        assert_eq!(format!("{}", a), "usr_6fTiplVKIi6bJFe8rTXPcu");
+        assert_eq!(format!("{:?}", a), "PrefixedFuid(\"usr_6fTiplVKIi6bJFe8rTXPcu\")");
+        assert_eq!(format!("{:?}", PrefixedFuid::<User>::from_fuid(Fuid::with_u128(1))), "PrefixedFuid(\"usr_1\")");
+
+        let b = PrefixedFuid::<Order>::from_fuid(Fuid::with_u128(1));
+        assert_eq!(format!("{}", b), "order-1");
+        assert_eq!(format!("{:#}", b), "order-0000000000000000000001");
+        assert_eq!(PrefixedFuid::<Order>::with_str("order-0000000000000000000001").unwrap(), b);
+    }
+
+    #[test]
+    fn test_wrong_prefix() {
+        assert!(matches!(PrefixedFuid::<User>::with_str("6fTiplVKIi6bJFe8rTXPcu"), Err(PrefixError::WrongPrefix)));
+        assert!(matches!(PrefixedFuid::<User>::with_str("order-1"), Err(PrefixError::WrongPrefix)));
+        assert!(matches!(PrefixedFuid::<User>::with_str("usr1"), Err(PrefixError::WrongPrefix)));
+        assert!(matches!(PrefixedFuid::<User>::with_str("usr-1"), Err(PrefixError::WrongPrefix)));
+        assert!(matches!(PrefixedFuid::<User>::with_str("usr_ab!"), Err(PrefixError::Fuid(_))));
+        assert!(matches!(PrefixedFuid::<User>::with_str("usr_"), Err(PrefixError::Fuid(base62::Empty))));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let a = PrefixedFuid::<User>::from_fuid(Fuid::with_u128(10));
+        let typed: TypedFuid<User> = a.into();
+        assert_eq!(typed, a.into_typed());
+        assert_eq!(PrefixedFuid::from(typed), a);
+        assert_eq!(PrefixedFuid::<User>::from(Uuid::from(a)), a);
+        assert_eq!(a.as_u128(), a.erase().as_u128());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = PrefixedFuid::<User>::from_fuid(Fuid::with_u128(10));
+        let b = serde_json::to_string(&a).unwrap();
+        assert_eq!(b, "\"usr_A\"");
+        let c: PrefixedFuid<User> = serde_json::from_str(&b).unwrap();
+        assert_eq!(a, c);
+        assert!(serde_json::from_str::<PrefixedFuid<User>>("\"A\"").is_err());
+
+        use serde::de::value::{BytesDeserializer, Error};
+        assert_eq!(PrefixedFuid::<User>::deserialize(BytesDeserializer::<Error>::new(b"usr_A")).unwrap(), a);
+        assert!(PrefixedFuid::<User>::deserialize(BytesDeserializer::<Error>::new(b"usr_\xff")).is_err());
+    }
+}
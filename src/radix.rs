@@ -0,0 +1,125 @@
+import_stdlib!();
+
+pub use self::DecodeError::*;
+
+/// An error returned when decoding a string with one of the alternative
+/// encodings.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The string contains a character outside the alphabet, at the given
+    /// one-based position.
+    InvalidByte(char, usize),
+    /// The string is empty.
+    Empty,
+    /// The string is longer than the encoding allows.
+    TooLong,
+    /// The string is shorter than the fixed length of the encoding.
+    TooShort,
+    /// The string encodes a number larger than `u128::MAX`.
+    ValueTooLarge,
+}
+
+impl Error for DecodeError {
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Marks bytes that are not digits in a decoding table.
+const INVALID: u8 = 0xff;
+
+/// The digits of a positional encoding in base `N`, and a table mapping each
+/// byte back to its value.
+pub(crate) struct Radix<const N: usize> {
+    digits: [u8; N],
+    values: [u8; 256],
+}
+
+impl<const N: usize> Radix<N> {
+    pub(crate) const fn new(digits: &[u8; N]) -> Self {
+        let mut values = [INVALID; 256];
+        let mut i = 0;
+        while i < N {
+            values[digits[i] as usize] = i as u8;
+            i += 1;
+        }
+        Self { digits: *digits, values }
+    }
+
+    /// Accepts the other case of every letter when decoding.
+    pub(crate) const fn case_insensitive(mut self) -> Self {
+        let mut i = 0;
+        while i < N {
+            let c = self.digits[i];
+            let other = if c.is_ascii_uppercase() { c.to_ascii_lowercase() } else { c.to_ascii_uppercase() };
+            if other != c && self.values[other as usize] == INVALID {
+                self.values[other as usize] = i as u8;
+            }
+            i += 1;
+        }
+        self
+    }
+
+    /// Decodes `alias` as the same value as the digit `c`.
+    pub(crate) const fn alias(mut self, alias: u8, c: u8) -> Self {
+        self.values[alias as usize] = self.values[c as usize];
+        self
+    }
+
+    /// Returns the value of the given digit, or `None` if it is not in the
+    /// alphabet.
+    pub(crate) const fn value(&self, c: u8) -> Option<u8> {
+        match self.values[c as usize] {
+            INVALID => None,
+            v => Some(v),
+        }
+    }
+
+    /// Writes the digits of the number to the end of the buffer, returning the
+    /// index of the first digit.
+    pub(crate) fn encode_digits(&self, mut num: u128, buf: &mut [u8]) -> usize {
+        let mut start = buf.len();
+        loop {
+            start -= 1;
+            buf[start] = self.digits[(num % N as u128) as usize];
+            num /= N as u128;
+            if num == 0 {
+                return start;
+            }
+        }
+    }
+
+    /// Decodes a string of at most `max_len` digits.
+    pub(crate) const fn decode(&self, string: &str, max_len: usize) -> Result<u128, DecodeError> {
+        let bytes = string.as_bytes();
+        if bytes.is_empty() {
+            return Err(Empty);
+        }
+        if bytes.len() > max_len {
+            return Err(TooLong);
+        }
+
+        let mut result: u128 = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            let v = match self.value(bytes[i]) {
+                Some(v) => v,
+                None => return Err(InvalidByte(bytes[i] as char, i + 1)),
+            };
+            result = match result.checked_mul(N as u128) {
+                Some(r) => match r.checked_add(v as u128) {
+                    Some(r) => r,
+                    None => return Err(ValueTooLarge),
+                },
+                None => return Err(ValueTooLarge),
+            };
+            i += 1;
+        }
+
+        Ok(result)
+    }
+}
@@ -3,35 +3,27 @@ pub mod with_std {
     pub use std::{fmt, str::FromStr};
 
     pub use std::string::String;
-    pub use std::vec::Vec;
     pub use std::error::Error;
     pub use std::string::ToString;
     pub use std::borrow::ToOwned;
+    pub use std::time::{Duration, SystemTime, UNIX_EPOCH};
 }
 
 #[cfg(not(feature = "std"))]
 pub mod without_std {
+    #[cfg(feature = "alloc")]
     extern crate alloc;
 
     pub use core::fmt::{self};
+    pub use core::str::FromStr;
+    pub use core::error::Error;
+    #[cfg(feature = "alloc")]
     pub use alloc::string::{String, ToString};
-    pub use alloc::vec::Vec;
-    pub use alloc::boxed::Box;
-    pub use alloc::str::FromStr;
+    #[cfg(feature = "alloc")]
     pub use alloc::borrow::ToOwned;
+    #[cfg(feature = "alloc")]
     #[allow(unused_imports)]
     pub use alloc::format;
-
-    pub trait Error: fmt::Debug + fmt::Display { }
-
-    impl<T> From<T> for Box<dyn Error>
-    where
-        T: Error + 'static,
-    {
-        fn from(err: T) -> Box<dyn Error> {
-            Box::new(err)
-        }
-    }
 }
 
 macro_rules! import_stdlib {
@@ -0,0 +1,253 @@
+import_stdlib!();
+
+use core::{cmp::Ordering, hash::{Hash, Hasher}, marker::PhantomData};
+use uuid::Uuid;
+use super::{base62, Fuid};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A FUID tagged with the type of entity it identifies.
+///
+/// `TypedFuid<User>` and `TypedFuid<Order>` have the same layout as `Fuid` and
+/// format, parse and serialize the same way, but cannot be compared with or
+/// assigned to each other. The tag type is never instantiated, so it can be
+/// any type, including an empty enum.
+///
+/// ```
+/// use fuid::{fuid, Fuid, TypedFuid};
+///
+/// struct User;
+/// struct Order;
+///
+/// type UserId = TypedFuid<User>;
+/// type OrderId = TypedFuid<Order>;
+///
+/// let user = UserId::with_u128(10);
+/// let order: OrderId = "A".parse().unwrap();
+/// assert_eq!(order.to_string(), "A");
+///
+/// // Conversions between tags, or to an untyped `Fuid`, are explicit.
+/// let erased: Fuid = user.erase();
+/// let cast: OrderId = user.cast();
+/// assert_eq!(erased, cast.erase());
+/// ```
+///
+/// ```compile_fail
+/// # use fuid::TypedFuid;
+/// # struct User;
+/// # struct Order;
+/// let user = TypedFuid::<User>::with_u128(1);
+/// let order = TypedFuid::<Order>::with_u128(1);
+/// assert!(user != order);
+/// ```
+#[repr(transparent)]
+pub struct TypedFuid<T: ?Sized> {
+    fuid: Fuid,
+    tag: PhantomData<fn() -> T>,
+}
+
+impl<T: ?Sized> TypedFuid<T> {
+    /// Creates a new, random FUID.
+    #[cfg(feature = "getrandom")]
+    pub fn new() -> Self {
+        Self::from_fuid(Fuid::new())
+    }
+
+    /// Creates a new, time-ordered FUID using the UUIDv7 layout.
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    pub fn new_v7() -> Self {
+        Self::from_fuid(Fuid::new_v7())
+    }
+
+    /// Tags the given FUID.
+    pub const fn from_fuid(fuid: Fuid) -> Self {
+        Self { fuid, tag: PhantomData }
+    }
+
+    /// Creates a new FUID from the given string.
+    pub fn with_str(s: &str) -> Result<Self, base62::DecodeError> {
+        Fuid::with_str(s).map(Self::from_fuid)
+    }
+
+    /// Creates a new FUID from the given u128.
+    pub const fn with_u128(i: u128) -> Self {
+        Self::from_fuid(Fuid::with_u128(i))
+    }
+
+    /// Returns the wrapped u128 value.
+    pub const fn as_u128(&self) -> u128 {
+        self.fuid.as_u128()
+    }
+
+    /// Returns the untagged FUID.
+    pub const fn erase(self) -> Fuid {
+        self.fuid
+    }
+
+    /// Returns the same FUID with a different tag.
+    pub const fn cast<U: ?Sized>(self) -> TypedFuid<U> {
+        TypedFuid::from_fuid(self.fuid)
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl<T: ?Sized> Default for TypedFuid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> Clone for TypedFuid<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for TypedFuid<T> {
+}
+
+impl<T: ?Sized> PartialEq for TypedFuid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fuid == other.fuid
+    }
+}
+
+impl<T: ?Sized> Eq for TypedFuid<T> {
+}
+
+impl<T: ?Sized> PartialOrd for TypedFuid<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> Ord for TypedFuid<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.fuid.cmp(&other.fuid)
+    }
+}
+
+impl<T: ?Sized> Hash for TypedFuid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fuid.hash(state)
+    }
+}
+
+impl<T: ?Sized> fmt::Display for TypedFuid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.fuid, f)
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for TypedFuid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedFuid")
+            .field(&self.fuid.encode_to(&mut [0; base62::MAX_LEN]))
+            .finish()
+    }
+}
+
+impl<T: ?Sized> FromStr for TypedFuid<T> {
+    type Err = base62::DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::with_str(s)
+    }
+}
+
+impl<T: ?Sized> TryFrom<&str> for TypedFuid<T> {
+    type Error = base62::DecodeError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        Self::with_str(val)
+    }
+}
+
+impl<T: ?Sized> From<Uuid> for TypedFuid<T> {
+    fn from(u: Uuid) -> Self {
+        Self::from_fuid(u.into())
+    }
+}
+
+impl<T: ?Sized> From<TypedFuid<T>> for Uuid {
+    fn from(f: TypedFuid<T>) -> Self {
+        f.fuid.into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: ?Sized> Deserialize<'de> for TypedFuid<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Fuid::deserialize(deserializer).map(Self::from_fuid)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: ?Sized> Serialize for TypedFuid<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.fuid.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate alloc;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    enum User {}
+    struct Order;
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(core::mem::size_of::<TypedFuid<User>>(), core::mem::size_of::<Fuid>());
+        assert_eq!(core::mem::align_of::<TypedFuid<User>>(), core::mem::align_of::<Fuid>());
+    }
+
+    #[test]
+    fn test_typed_fuid() {
+        let a: TypedFuid<User> = "6fTiplVKIi6bJFe8rTXPcu".parse().unwrap();
+        let b = TypedFuid::<User>::with_str("6fTiplVKIi6bJFe8rTXPcu").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(format!("{}", a), "6fTiplVKIi6bJFe8rTXPcu");
+        assert_eq!(format!("{:?}", a), "TypedFuid(\"6fTiplVKIi6bJFe8rTXPcu\")");
+        assert_eq!(format!("{:#}", TypedFuid::<User>::with_u128(1)), "0000000000000000000001");
+        assert!(TypedFuid::<User>::try_from("ab!").is_err());
+        #[cfg(feature = "getrandom")]
+        assert_ne!(TypedFuid::<User>::new(), a);
+        assert!(TypedFuid::<User>::with_u128(1) < TypedFuid::<User>::with_u128(2));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let user = TypedFuid::<User>::with_u128(10);
+        let order: TypedFuid<Order> = user.cast();
+        assert_eq!(order.as_u128(), 10);
+        assert_eq!(user.erase(), order.erase());
+        assert_eq!(TypedFuid::<Order>::from_fuid(user.erase()), order);
+
+        let uuid = Uuid::from(user);
+        assert_eq!(uuid.as_u128(), 10);
+        assert_eq!(TypedFuid::<User>::from(uuid), user);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = TypedFuid::<User>::with_u128(10);
+        let b = serde_json::to_string(&a).unwrap();
+        assert_eq!(b, serde_json::to_string(&a.erase()).unwrap());
+        let c: TypedFuid<User> = serde_json::from_str(&b).unwrap();
+        assert_eq!(a, c);
+    }
+}
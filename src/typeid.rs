@@ -0,0 +1,248 @@
+//! Conversions between FUIDs and [TypeID](https://github.com/jetify-com/typeid)s.
+//! `TypeId` is kept in this module, rather than the crate root, so that it
+//! does not clash with `core::any::TypeId`.
+
+import_stdlib!();
+
+use crate::radix::Radix;
+use super::{base32_crockford, Fuid};
+
+/// The length of the suffix of every TypeID.
+const SUFFIX_LEN: usize = 26;
+
+/// The longest prefix the TypeID specification allows.
+const MAX_PREFIX_LEN: usize = 63;
+
+/// TypeID suffixes use Crockford's alphabet in lower case only, without the
+/// aliases that `base32_crockford` accepts.
+const RADIX: Radix<32> = Radix::new(b"0123456789abcdefghjkmnpqrstvwxyz");
+
+/// An error returned when parsing a TypeID, or when formatting one with an
+/// invalid prefix.
+#[derive(Debug)]
+pub enum TypeIdError {
+    /// The prefix is longer than 63 characters, contains characters other than
+    /// lower case ASCII letters and underscores, or starts or ends with an
+    /// underscore. A separator with an empty prefix is also invalid.
+    InvalidPrefix,
+    /// The suffix is not 26 characters of lower case Crockford's Base32, or
+    /// encodes a number larger than `u128::MAX`.
+    InvalidSuffix(base32_crockford::DecodeError),
+}
+
+impl Error for TypeIdError {
+}
+
+impl fmt::Display for TypeIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeIdError::InvalidPrefix => f.write_str("invalid TypeID prefix"),
+            TypeIdError::InvalidSuffix(e) => write!(f, "invalid TypeID suffix: {}", e),
+        }
+    }
+}
+
+/// A FUID with a type prefix, in the [TypeID](https://github.com/jetify-com/typeid)
+/// format: the prefix, an underscore and 26 characters of lower case
+/// Crockford's Base32, such as `user_01h455vb4pex5vsknk084sn02q`. If the
+/// prefix is empty, the underscore is omitted.
+///
+/// The prefix is borrowed, so parsing does not allocate. Created by
+/// `Fuid::typeid` or `TypeId::parse`.
+///
+/// ```
+/// use fuid::{typeid::TypeId, Fuid};
+///
+/// let id = TypeId::parse("user_01h455vb4pex5vsknk084sn02q").unwrap();
+/// assert_eq!(id.prefix(), "user");
+/// assert_eq!(id.as_fuid().hyphenated().to_string(), "01890a5d-ac96-774b-bcce-b302099a8057");
+///
+/// let fuid: Fuid = id.into();
+/// assert_eq!(fuid.typeid("user").unwrap().to_string(), "user_01h455vb4pex5vsknk084sn02q");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypeId<'a> {
+    prefix: &'a str,
+    fuid: Fuid,
+}
+
+impl<'a> TypeId<'a> {
+    /// Creates a TypeID from the prefix and FUID, checking that the prefix is
+    /// valid.
+    pub const fn new(prefix: &'a str, fuid: Fuid) -> Result<Self, TypeIdError> {
+        if !is_valid_prefix(prefix) {
+            return Err(TypeIdError::InvalidPrefix);
+        }
+        Ok(Self { prefix, fuid })
+    }
+
+    /// Parses a TypeID. The suffix must be in lower case, and the aliases of
+    /// Crockford's Base32 are not accepted.
+    pub fn parse(s: &'a str) -> Result<Self, TypeIdError> {
+        let (prefix, suffix) = match s.rsplit_once('_') {
+            Some(("", _)) => return Err(TypeIdError::InvalidPrefix),
+            Some((prefix, suffix)) => (prefix, suffix),
+            None => ("", s),
+        };
+        if suffix.len() < SUFFIX_LEN {
+            return Err(TypeIdError::InvalidSuffix(base32_crockford::TooShort));
+        }
+        let n = RADIX.decode(suffix, SUFFIX_LEN).map_err(TypeIdError::InvalidSuffix)?;
+        Self::new(prefix, Fuid::with_u128(n))
+    }
+
+    /// Returns the prefix, without the separator.
+    pub const fn prefix(&self) -> &'a str {
+        self.prefix
+    }
+
+    /// Returns the FUID.
+    pub const fn as_fuid(&self) -> &Fuid {
+        &self.fuid
+    }
+}
+
+impl fmt::Display for TypeId<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.prefix.is_empty() {
+            f.write_str(self.prefix)?;
+            f.write_str("_")?;
+        }
+        let mut buf = [b'0'; SUFFIX_LEN];
+        RADIX.encode_digits(self.fuid.as_u128(), &mut buf);
+        f.write_str(core::str::from_utf8(&buf).unwrap())
+    }
+}
+
+impl From<TypeId<'_>> for Fuid {
+    fn from(t: TypeId<'_>) -> Self {
+        t.fuid
+    }
+}
+
+/// Returns whether the prefix follows the TypeID specification.
+const fn is_valid_prefix(prefix: &str) -> bool {
+    let bytes = prefix.as_bytes();
+    if bytes.len() > MAX_PREFIX_LEN {
+        return false;
+    }
+    if let [b'_', ..] | [.., b'_'] = bytes {
+        return false;
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        if !matches!(bytes[i], b'a'..=b'z' | b'_') {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl Fuid {
+    /// Parses a TypeID, returning its prefix and FUID.
+    ///
+    /// ```
+    /// use fuid::Fuid;
+    ///
+    /// let (prefix, id) = Fuid::from_typeid("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(prefix, "user");
+    /// assert_eq!(id.typeid(prefix).unwrap().to_string(), "user_01h455vb4pex5vsknk084sn02q");
+    /// ```
+    pub fn from_typeid(s: &str) -> Result<(&str, Fuid), TypeIdError> {
+        TypeId::parse(s).map(|t| (t.prefix, t.fuid))
+    }
+
+    /// Returns an adapter that formats the FUID as a TypeID with the given
+    /// prefix, or an error if the prefix is not valid.
+    pub const fn typeid(self, prefix: &str) -> Result<TypeId<'_>, TypeIdError> {
+        TypeId::new(prefix, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate alloc;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    // The valid and invalid test vectors of the TypeID specification.
+
+    const VALID: &[(&str, &str, u128)] = &[
+        ("nil", "00000000000000000000000000", 0),
+        ("one", "00000000000000000000000001", 1),
+        ("ten", "0000000000000000000000000a", 10),
+        ("sixteen", "0000000000000000000000000g", 16),
+        ("thirty-two", "00000000000000000000000010", 32),
+        ("max-valid", "7zzzzzzzzzzzzzzzzzzzzzzzzz", u128::MAX),
+        ("valid-alphabet", "prefix_0123456789abcdefghjkmnpqrs", 0x0110c853_1d09_52d8_d73e_1194e95b5f19),
+        ("valid-uuidv7", "prefix_01h455vb4pex5vsknk084sn02q", 0x01890a5d_ac96_774b_bcce_b302099a8057),
+        ("prefix-underscore", "pre_fix_00000000000000000000000000", 0),
+    ];
+
+    const INVALID: &[(&str, &str)] = &[
+        ("prefix-uppercase", "PREFIX_00000000000000000000000000"),
+        ("prefix-numeric", "12345_00000000000000000000000000"),
+        ("prefix-period", "pre.fix_00000000000000000000000000"),
+        ("prefix-non-ascii", "prΣfix_00000000000000000000000000"),
+        ("prefix-spaces", "  prefix_00000000000000000000000000"),
+        ("prefix-64-chars", "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijkl_00000000000000000000000000"),
+        ("separator-empty-prefix", "_00000000000000000000000000"),
+        ("separator-empty", "_"),
+        ("suffix-short", "prefix_1234567890123456789012345"),
+        ("suffix-long", "prefix_123456789012345678901234567"),
+        ("suffix-spaces", "prefix_1234567890123456789012345 "),
+        ("suffix-uppercase", "prefix_0123456789ABCDEFGHJKMNPQRS"),
+        ("suffix-hyphens", "prefix_123456789-123456789-123456"),
+        ("suffix-wrong-alphabet", "prefix_ooooooiiiiiiuuuuuuulllllll"),
+        ("suffix-ambiguous-crockford", "prefix_i23456789ooooooooooooooooo"),
+        ("suffix-hyphens-crockford", "prefix_123456789-0123456789-0123456"),
+        ("suffix-overflow", "prefix_8zzzzzzzzzzzzzzzzzzzzzzzzz"),
+        ("prefix-underscore-start", "_prefix_00000000000000000000000000"),
+        ("prefix-underscore-end", "prefix__00000000000000000000000000"),
+        ("empty", ""),
+    ];
+
+    #[test]
+    fn test_valid() {
+        for &(name, s, n) in VALID {
+            let t = TypeId::parse(s).unwrap_or_else(|e| panic!("{}: {}", name, e));
+            assert_eq!(t.as_fuid().as_u128(), n, "{}", name);
+            assert_eq!(format!("{}", t), s, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        for &(name, s) in INVALID {
+            assert!(TypeId::parse(s).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(TypeId::parse("_00000000000000000000000000"), Err(TypeIdError::InvalidPrefix)));
+        assert!(matches!(TypeId::parse("Prefix_00000000000000000000000000"), Err(TypeIdError::InvalidPrefix)));
+        assert!(matches!(TypeId::parse("prefix_"), Err(TypeIdError::InvalidSuffix(base32_crockford::TooShort))));
+        assert!(matches!(TypeId::parse("prefix_000000000000000000000000000"), Err(TypeIdError::InvalidSuffix(base32_crockford::TooLong))));
+        assert!(matches!(TypeId::parse("prefix_0000000000000000000000000O"), Err(TypeIdError::InvalidSuffix(base32_crockford::InvalidByte('O', 26)))));
+        assert!(matches!(TypeId::parse("prefix_80000000000000000000000000"), Err(TypeIdError::InvalidSuffix(base32_crockford::ValueTooLarge))));
+    }
+
+    #[test]
+    fn test_fuid_conversions() {
+        let id = Fuid::with_u128(0x01890a5d_ac96_774b_bcce_b302099a8057);
+        assert_eq!(format!("{}", id.typeid("user").unwrap()), "user_01h455vb4pex5vsknk084sn02q");
+        assert_eq!(format!("{}", id.typeid("").unwrap()), "01h455vb4pex5vsknk084sn02q");
+        assert!(matches!(id.typeid("User"), Err(TypeIdError::InvalidPrefix)));
+        assert!(matches!(id.typeid("user_"), Err(TypeIdError::InvalidPrefix)));
+
+        assert_eq!(Fuid::from_typeid("user_01h455vb4pex5vsknk084sn02q").unwrap(), ("user", id));
+        assert_eq!(Fuid::from_typeid("01h455vb4pex5vsknk084sn02q").unwrap(), ("", id));
+        assert_eq!(Fuid::from(TypeId::new("user", id).unwrap()), id);
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "derive")]
+
+use fuid::{base62, fuid, Fuid, FuidNewtype, PrefixError};
+use uuid::Uuid;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, FuidNewtype)]
+struct AccountId(Fuid);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, FuidNewtype)]
+#[fuid(prefix = "acct")]
+struct PrefixedAccountId(pub Fuid);
+
+mod ids {
+    pub use ::fuid as reexported;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, FuidNewtype)]
+#[fuid(crate = "ids::reexported", prefix = "org")]
+struct OrgId(ids::reexported::Fuid);
+
+#[test]
+fn test_newtype() {
+    let id: AccountId = "6fTiplVKIi6bJFe8rTXPcu".parse().unwrap();
+    assert_eq!(*id.as_fuid(), fuid!("6fTiplVKIi6bJFe8rTXPcu"));
+    assert_eq!(id.to_string(), "6fTiplVKIi6bJFe8rTXPcu");
+    assert_eq!(format!("{:#}", AccountId::from(fuid!(1))), "0000000000000000000001");
+    assert_eq!(format!("{:?}", id), "AccountId(\"6fTiplVKIi6bJFe8rTXPcu\")");
+    assert!(matches!(AccountId::try_from("ab!"), Err(base62::InvalidBase62Byte('!', 3))));
+    #[cfg(feature = "getrandom")]
+    assert_ne!(AccountId::new(), AccountId::new());
+}
+
+#[test]
+fn test_prefixed_newtype() {
+    let id: PrefixedAccountId = "acct_6fTiplVKIi6bJFe8rTXPcu".parse().unwrap();
+    assert_eq!(id.0, fuid!("6fTiplVKIi6bJFe8rTXPcu"));
+    assert_eq!(id.to_string(), "acct_6fTiplVKIi6bJFe8rTXPcu");
+    assert_eq!(format!("{:?}", id), "PrefixedAccountId(\"acct_6fTiplVKIi6bJFe8rTXPcu\")");
+    assert!(matches!(PrefixedAccountId::try_from("6fTiplVKIi6bJFe8rTXPcu"), Err(PrefixError::WrongPrefix)));
+    assert!(matches!(PrefixedAccountId::try_from("acct_ab!"), Err(PrefixError::Fuid(_))));
+}
+
+#[test]
+fn test_conversions() {
+    let fuid = fuid!("3k9FL4LZe71geQdbOyCvz3");
+    let id = AccountId::from(fuid);
+    assert_eq!(Fuid::from(id), fuid);
+    assert_eq!(AccountId::from(Uuid::from(id)), id);
+
+    let id = PrefixedAccountId::from(fuid);
+    assert_eq!(Fuid::from(id), fuid);
+    assert_eq!(PrefixedAccountId::from(Uuid::from(id)), id);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let id = AccountId::from(fuid!(10));
+    assert_eq!(serde_json::to_string(&id).unwrap(), "\"A\"");
+    assert_eq!(serde_json::from_str::<AccountId>("\"A\"").unwrap(), id);
+
+    let id = PrefixedAccountId::from(fuid!(10));
+    assert_eq!(serde_json::to_string(&id).unwrap(), "\"acct_A\"");
+    assert_eq!(serde_json::from_str::<PrefixedAccountId>("\"acct_A\"").unwrap(), id);
+    assert!(serde_json::from_str::<PrefixedAccountId>("\"A\"").is_err());
+}
+
+#[test]
+fn test_crate_path() {
+    let id: OrgId = "org_6fTiplVKIi6bJFe8rTXPcu".parse().unwrap();
+    assert_eq!(id.0, fuid!("6fTiplVKIi6bJFe8rTXPcu"));
+    assert_eq!(format!("{:?}", id), "OrgId(\"org_6fTiplVKIi6bJFe8rTXPcu\")");
+}